@@ -0,0 +1,537 @@
+//! German word lists for [`Locale::De`](crate::Locale::De).
+//!
+//! The lists hold the English words that have a translation, so tags and filters work exactly as
+//! they do in English; [`translate`] swaps in the German words when a name is rendered.
+
+use crate::{NamePair, WordLists};
+
+/// Shared adjectives with a German translation, inflected by [`translate`] to agree with the noun.
+pub(crate) const ADJECTIVES: &[&str] = &[
+    "ancient", "angry", "arctic", "atomic", "black", "blue", "bold", "bright", "brown", "calm",
+    "cheerful", "clever", "cold", "creamy", "crisp", "curious", "electric", "fierce", "fluffy",
+    "fresh", "frosty", "gentle", "golden", "grand", "gray", "green", "grumpy", "happy", "hot",
+    "huge", "icy", "intrepid", "juicy", "little", "lively", "lonely", "magnetic", "merry",
+    "mighty", "misty", "nervous", "new", "nimble", "old", "playful", "polar", "proud", "quick",
+    "quiet", "radiant", "rainy", "red", "round", "sad", "salty", "shiny", "shy", "silent",
+    "silver", "sleepy", "smoky", "smooth", "spicy", "sticky", "stormy", "sunny", "sweet", "swift",
+    "tiny", "tropical", "warm", "white", "wild", "witty", "yellow", "zesty",
+];
+
+/// Food words with a German translation.
+pub(crate) const FOOD_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "almond",
+        "apple",
+        "apricot",
+        "asparagus",
+        "avocado",
+        "bacon",
+        "banana",
+        "basil",
+        "beef",
+        "blackberry",
+        "blueberry",
+        "bread",
+        "broccoli",
+        "bun",
+        "cabbage",
+        "cake",
+        "candy",
+        "cantaloupe",
+        "caramel",
+        "carrot",
+        "cauliflower",
+        "celery",
+        "cherry",
+        "chicken",
+        "coconut",
+        "cod",
+        "cookie",
+        "croissant",
+        "cucumber",
+        "currant",
+        "date",
+        "duck",
+        "dumpling",
+        "eel",
+        "eggplant",
+        "elderberry",
+        "fig",
+        "flounder",
+        "garlic",
+        "ginger",
+        "gooseberry",
+        "grape",
+        "ham",
+        "hazelnut",
+        "herring",
+        "honey",
+        "jelly",
+        "kiwi",
+        "lamb",
+        "leek",
+        "lemon",
+        "lentil",
+        "lettuce",
+        "lime",
+        "lingonberry",
+        "lobster",
+        "mackerel",
+        "mandarin",
+        "mango",
+        "muffin",
+        "noodle",
+        "octopus",
+        "olive",
+        "omelet",
+        "onion",
+        "orange",
+        "oyster",
+        "pancake",
+        "papaya",
+        "peach",
+        "peanut",
+        "pear",
+        "pepper",
+        "perch",
+        "pie",
+        "pike",
+        "pineapple",
+        "pistachio",
+        "pizza",
+        "plum",
+        "pomegranate",
+        "potato",
+        "pretzel",
+        "prune",
+        "radish",
+        "raisin",
+        "raspberry",
+        "sardine",
+        "sausage",
+        "shrimp",
+        "sole",
+        "spinach",
+        "squash",
+        "squid",
+        "steak",
+        "stew",
+        "strawberry",
+        "sturgeon",
+        "tomato",
+        "truffle",
+        "turbot",
+        "turkey",
+        "turnip",
+        "veal",
+        "waffle",
+        "walnut",
+        "watermelon",
+        "yogurt",
+        "zucchini",
+    ],
+};
+
+/// Sci-fi words with a German translation.
+pub(crate) const SCIFI_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "android",
+        "anomaly",
+        "asteroid",
+        "astronaut",
+        "aurora",
+        "beacon",
+        "capsule",
+        "citadel",
+        "comet",
+        "constellation",
+        "cosmos",
+        "cruiser",
+        "cyborg",
+        "droid",
+        "eclipse",
+        "engine",
+        "enigma",
+        "exoplanet",
+        "frontier",
+        "galaxy",
+        "hyperdrive",
+        "jetpack",
+        "lunar base",
+        "magnetar",
+        "meteor",
+        "meteorite",
+        "module",
+        "mothership",
+        "nebula",
+        "neutron",
+        "nova",
+        "observatory",
+        "orbiter",
+        "outpost",
+        "photon",
+        "pioneer",
+        "plasma",
+        "portal",
+        "probe",
+        "pulsar",
+        "quasar",
+        "reactor",
+        "rocket",
+        "satellite",
+        "scout",
+        "shuttle",
+        "singularity",
+        "solar wind",
+        "space station",
+        "star",
+        "starship",
+        "station",
+        "supernova",
+        "thruster",
+        "wormhole",
+        "zenith",
+        "zodiac",
+        "hydrogen",
+        "helium",
+        "carbon",
+        "oxygen",
+        "neon",
+        "titanium",
+        "iron",
+        "copper",
+        "platinum",
+        "uranium",
+        "obsidian",
+        "quartz",
+        "diamond",
+        "sapphire",
+        "glass",
+        "alloy",
+    ],
+};
+
+#[derive(Clone, Copy)]
+enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+/// German for `pair`, with the adjective in the strong nominative form for the noun's gender
+/// (`Glänzender Apfel`, `Glänzende Mango`, `Glänzendes Brot`); words without a translation are
+/// kept.
+pub(crate) fn translate(pair: NamePair) -> NamePair {
+    let Some((noun, gender)) = noun(pair.noun) else {
+        return pair;
+    };
+    NamePair {
+        adjective: adjective(pair.adjective).map_or(pair.adjective, |forms| forms[gender as usize]),
+        noun,
+    }
+}
+
+/// Masculine, feminine and neuter forms of an adjective.
+fn adjective(english: &str) -> Option<[&'static str; 3]> {
+    Some(match english {
+        "ancient" => ["uralter", "uralte", "uraltes"],
+        "angry" => ["wütender", "wütende", "wütendes"],
+        "arctic" => ["arktischer", "arktische", "arktisches"],
+        "atomic" => ["atomarer", "atomare", "atomares"],
+        "black" => ["schwarzer", "schwarze", "schwarzes"],
+        "blue" => ["blauer", "blaue", "blaues"],
+        "bold" => ["kühner", "kühne", "kühnes"],
+        "bright" => ["heller", "helle", "helles"],
+        "brown" => ["brauner", "braune", "braunes"],
+        "calm" => ["ruhiger", "ruhige", "ruhiges"],
+        "cheerful" => ["heiterer", "heitere", "heiteres"],
+        "clever" => ["kluger", "kluge", "kluges"],
+        "cold" => ["kalter", "kalte", "kaltes"],
+        "creamy" => ["cremiger", "cremige", "cremiges"],
+        "crisp" => ["knackiger", "knackige", "knackiges"],
+        "curious" => ["neugieriger", "neugierige", "neugieriges"],
+        "electric" => ["elektrischer", "elektrische", "elektrisches"],
+        "fierce" => ["grimmiger", "grimmige", "grimmiges"],
+        "fluffy" => ["flauschiger", "flauschige", "flauschiges"],
+        "fresh" => ["frischer", "frische", "frisches"],
+        "frosty" => ["frostiger", "frostige", "frostiges"],
+        "gentle" => ["sanfter", "sanfte", "sanftes"],
+        "golden" => ["goldener", "goldene", "goldenes"],
+        "grand" => ["großartiger", "großartige", "großartiges"],
+        "gray" => ["grauer", "graue", "graues"],
+        "green" => ["grüner", "grüne", "grünes"],
+        "grumpy" => ["mürrischer", "mürrische", "mürrisches"],
+        "happy" => ["fröhlicher", "fröhliche", "fröhliches"],
+        "hot" => ["heißer", "heiße", "heißes"],
+        "huge" => ["riesiger", "riesige", "riesiges"],
+        "icy" => ["eisiger", "eisige", "eisiges"],
+        "intrepid" => ["mutiger", "mutige", "mutiges"],
+        "juicy" => ["saftiger", "saftige", "saftiges"],
+        "little" => ["kleiner", "kleine", "kleines"],
+        "lively" => ["lebhafter", "lebhafte", "lebhaftes"],
+        "lonely" => ["einsamer", "einsame", "einsames"],
+        "magnetic" => ["magnetischer", "magnetische", "magnetisches"],
+        "merry" => ["lustiger", "lustige", "lustiges"],
+        "mighty" => ["mächtiger", "mächtige", "mächtiges"],
+        "misty" => ["nebliger", "neblige", "nebliges"],
+        "nervous" => ["nervöser", "nervöse", "nervöses"],
+        "new" => ["neuer", "neue", "neues"],
+        "nimble" => ["wendiger", "wendige", "wendiges"],
+        "old" => ["alter", "alte", "altes"],
+        "playful" => ["verspielter", "verspielte", "verspieltes"],
+        "polar" => ["polarer", "polare", "polares"],
+        "proud" => ["stolzer", "stolze", "stolzes"],
+        "quick" => ["schneller", "schnelle", "schnelles"],
+        "quiet" => ["leiser", "leise", "leises"],
+        "radiant" => ["strahlender", "strahlende", "strahlendes"],
+        "rainy" => ["regnerischer", "regnerische", "regnerisches"],
+        "red" => ["roter", "rote", "rotes"],
+        "round" => ["runder", "runde", "rundes"],
+        "sad" => ["trauriger", "traurige", "trauriges"],
+        "salty" => ["salziger", "salzige", "salziges"],
+        "shiny" => ["glänzender", "glänzende", "glänzendes"],
+        "shy" => ["schüchterner", "schüchterne", "schüchternes"],
+        "silent" => ["stiller", "stille", "stilles"],
+        "silver" => ["silberner", "silberne", "silbernes"],
+        "sleepy" => ["schläfriger", "schläfrige", "schläfriges"],
+        "smoky" => ["rauchiger", "rauchige", "rauchiges"],
+        "smooth" => ["glatter", "glatte", "glattes"],
+        "spicy" => ["scharfer", "scharfe", "scharfes"],
+        "sticky" => ["klebriger", "klebrige", "klebriges"],
+        "stormy" => ["stürmischer", "stürmische", "stürmisches"],
+        "sunny" => ["sonniger", "sonnige", "sonniges"],
+        "sweet" => ["süßer", "süße", "süßes"],
+        "swift" => ["flinker", "flinke", "flinkes"],
+        "tiny" => ["winziger", "winzige", "winziges"],
+        "tropical" => ["tropischer", "tropische", "tropisches"],
+        "warm" => ["warmer", "warme", "warmes"],
+        "white" => ["weißer", "weiße", "weißes"],
+        "wild" => ["wilder", "wilde", "wildes"],
+        "witty" => ["witziger", "witzige", "witziges"],
+        "yellow" => ["gelber", "gelbe", "gelbes"],
+        "zesty" => ["spritziger", "spritzige", "spritziges"],
+        _ => return None,
+    })
+}
+
+fn noun(english: &str) -> Option<(&'static str, Gender)> {
+    use Gender::*;
+    Some(match english {
+        "almond" => ("Mandel", Feminine),
+        "apple" => ("Apfel", Masculine),
+        "apricot" => ("Aprikose", Feminine),
+        "asparagus" => ("Spargel", Masculine),
+        "avocado" => ("Avocado", Feminine),
+        "bacon" => ("Speck", Masculine),
+        "banana" => ("Banane", Feminine),
+        "basil" => ("Basilikum", Neuter),
+        "beef" => ("Rindfleisch", Neuter),
+        "blackberry" => ("Brombeere", Feminine),
+        "blueberry" => ("Heidelbeere", Feminine),
+        "bread" => ("Brot", Neuter),
+        "broccoli" => ("Brokkoli", Masculine),
+        "bun" => ("Brötchen", Neuter),
+        "cabbage" => ("Kohl", Masculine),
+        "cake" => ("Kuchen", Masculine),
+        "candy" => ("Bonbon", Neuter),
+        "cantaloupe" => ("Melone", Feminine),
+        "caramel" => ("Karamell", Masculine),
+        "carrot" => ("Karotte", Feminine),
+        "cauliflower" => ("Blumenkohl", Masculine),
+        "celery" => ("Sellerie", Masculine),
+        "cherry" => ("Kirsche", Feminine),
+        "chicken" => ("Hähnchen", Neuter),
+        "coconut" => ("Kokosnuss", Feminine),
+        "cod" => ("Kabeljau", Masculine),
+        "cookie" => ("Keks", Masculine),
+        "croissant" => ("Croissant", Neuter),
+        "cucumber" => ("Gurke", Feminine),
+        "currant" => ("Johannisbeere", Feminine),
+        "date" => ("Dattel", Feminine),
+        "duck" => ("Ente", Feminine),
+        "dumpling" => ("Knödel", Masculine),
+        "eel" => ("Aal", Masculine),
+        "eggplant" => ("Aubergine", Feminine),
+        "elderberry" => ("Holunderbeere", Feminine),
+        "fig" => ("Feige", Feminine),
+        "flounder" => ("Flunder", Feminine),
+        "garlic" => ("Knoblauch", Masculine),
+        "ginger" => ("Ingwer", Masculine),
+        "gooseberry" => ("Stachelbeere", Feminine),
+        "grape" => ("Traube", Feminine),
+        "ham" => ("Schinken", Masculine),
+        "hazelnut" => ("Haselnuss", Feminine),
+        "herring" => ("Hering", Masculine),
+        "honey" => ("Honig", Masculine),
+        "jelly" => ("Gelee", Neuter),
+        "kiwi" => ("Kiwi", Feminine),
+        "lamb" => ("Lamm", Neuter),
+        "leek" => ("Lauch", Masculine),
+        "lemon" => ("Zitrone", Feminine),
+        "lentil" => ("Linse", Feminine),
+        "lettuce" => ("Salat", Masculine),
+        "lime" => ("Limette", Feminine),
+        "lingonberry" => ("Preiselbeere", Feminine),
+        "lobster" => ("Hummer", Masculine),
+        "mackerel" => ("Makrele", Feminine),
+        "mandarin" => ("Mandarine", Feminine),
+        "mango" => ("Mango", Feminine),
+        "muffin" => ("Muffin", Masculine),
+        "noodle" => ("Nudel", Feminine),
+        "octopus" => ("Krake", Masculine),
+        "olive" => ("Olive", Feminine),
+        "omelet" => ("Omelett", Neuter),
+        "onion" => ("Zwiebel", Feminine),
+        "orange" => ("Orange", Feminine),
+        "oyster" => ("Auster", Feminine),
+        "pancake" => ("Pfannkuchen", Masculine),
+        "papaya" => ("Papaya", Feminine),
+        "peach" => ("Pfirsich", Masculine),
+        "peanut" => ("Erdnuss", Feminine),
+        "pear" => ("Birne", Feminine),
+        "pepper" => ("Paprika", Feminine),
+        "perch" => ("Barsch", Masculine),
+        "pie" => ("Torte", Feminine),
+        "pike" => ("Hecht", Masculine),
+        "pineapple" => ("Ananas", Feminine),
+        "pistachio" => ("Pistazie", Feminine),
+        "pizza" => ("Pizza", Feminine),
+        "plum" => ("Pflaume", Feminine),
+        "pomegranate" => ("Granatapfel", Masculine),
+        "potato" => ("Kartoffel", Feminine),
+        "pretzel" => ("Brezel", Feminine),
+        "prune" => ("Backpflaume", Feminine),
+        "radish" => ("Radieschen", Neuter),
+        "raisin" => ("Rosine", Feminine),
+        "raspberry" => ("Himbeere", Feminine),
+        "sardine" => ("Sardine", Feminine),
+        "sausage" => ("Wurst", Feminine),
+        "shrimp" => ("Garnele", Feminine),
+        "sole" => ("Seezunge", Feminine),
+        "spinach" => ("Spinat", Masculine),
+        "squash" => ("Kürbis", Masculine),
+        "squid" => ("Tintenfisch", Masculine),
+        "steak" => ("Steak", Neuter),
+        "stew" => ("Eintopf", Masculine),
+        "strawberry" => ("Erdbeere", Feminine),
+        "sturgeon" => ("Stör", Masculine),
+        "tomato" => ("Tomate", Feminine),
+        "truffle" => ("Trüffel", Masculine),
+        "turbot" => ("Steinbutt", Masculine),
+        "turkey" => ("Truthahn", Masculine),
+        "turnip" => ("Rübe", Feminine),
+        "veal" => ("Kalbfleisch", Neuter),
+        "waffle" => ("Waffel", Feminine),
+        "walnut" => ("Walnuss", Feminine),
+        "watermelon" => ("Wassermelone", Feminine),
+        "yogurt" => ("Joghurt", Masculine),
+        "zucchini" => ("Zucchini", Feminine),
+        "android" => ("Android", Masculine),
+        "anomaly" => ("Anomalie", Feminine),
+        "asteroid" => ("Asteroid", Masculine),
+        "astronaut" => ("Astronaut", Masculine),
+        "aurora" => ("Polarlicht", Neuter),
+        "beacon" => ("Leuchtfeuer", Neuter),
+        "capsule" => ("Kapsel", Feminine),
+        "citadel" => ("Zitadelle", Feminine),
+        "comet" => ("Komet", Masculine),
+        "constellation" => ("Sternbild", Neuter),
+        "cosmos" => ("Kosmos", Masculine),
+        "cruiser" => ("Kreuzer", Masculine),
+        "cyborg" => ("Cyborg", Masculine),
+        "droid" => ("Droide", Masculine),
+        "eclipse" => ("Finsternis", Feminine),
+        "engine" => ("Triebwerk", Neuter),
+        "enigma" => ("Rätsel", Neuter),
+        "exoplanet" => ("Exoplanet", Masculine),
+        "frontier" => ("Grenze", Feminine),
+        "galaxy" => ("Galaxie", Feminine),
+        "hyperdrive" => ("Hyperantrieb", Masculine),
+        "jetpack" => ("Raketenrucksack", Masculine),
+        "lunar base" => ("Mondbasis", Feminine),
+        "magnetar" => ("Magnetar", Masculine),
+        "meteor" => ("Meteor", Masculine),
+        "meteorite" => ("Meteorit", Masculine),
+        "module" => ("Modul", Neuter),
+        "mothership" => ("Mutterschiff", Neuter),
+        "nebula" => ("Nebel", Masculine),
+        "neutron" => ("Neutron", Neuter),
+        "nova" => ("Nova", Feminine),
+        "observatory" => ("Sternwarte", Feminine),
+        "orbiter" => ("Orbiter", Masculine),
+        "outpost" => ("Außenposten", Masculine),
+        "photon" => ("Photon", Neuter),
+        "pioneer" => ("Pionier", Masculine),
+        "plasma" => ("Plasma", Neuter),
+        "portal" => ("Portal", Neuter),
+        "probe" => ("Sonde", Feminine),
+        "pulsar" => ("Pulsar", Masculine),
+        "quasar" => ("Quasar", Masculine),
+        "reactor" => ("Reaktor", Masculine),
+        "rocket" => ("Rakete", Feminine),
+        "satellite" => ("Satellit", Masculine),
+        "scout" => ("Späher", Masculine),
+        "shuttle" => ("Raumfähre", Feminine),
+        "singularity" => ("Singularität", Feminine),
+        "solar wind" => ("Sonnenwind", Masculine),
+        "space station" => ("Raumstation", Feminine),
+        "star" => ("Stern", Masculine),
+        "starship" => ("Raumschiff", Neuter),
+        "station" => ("Station", Feminine),
+        "supernova" => ("Supernova", Feminine),
+        "thruster" => ("Düse", Feminine),
+        "wormhole" => ("Wurmloch", Neuter),
+        "zenith" => ("Zenit", Masculine),
+        "zodiac" => ("Tierkreis", Masculine),
+        "hydrogen" => ("Wasserstoff", Masculine),
+        "helium" => ("Helium", Neuter),
+        "carbon" => ("Kohlenstoff", Masculine),
+        "oxygen" => ("Sauerstoff", Masculine),
+        "neon" => ("Neon", Neuter),
+        "titanium" => ("Titan", Neuter),
+        "iron" => ("Eisen", Neuter),
+        "copper" => ("Kupfer", Neuter),
+        "platinum" => ("Platin", Neuter),
+        "uranium" => ("Uran", Neuter),
+        "obsidian" => ("Obsidian", Masculine),
+        "quartz" => ("Quarz", Masculine),
+        "diamond" => ("Diamant", Masculine),
+        "sapphire" => ("Saphir", Masculine),
+        "glass" => ("Glas", Neuter),
+        "alloy" => ("Legierung", Feminine),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn every_listed_word_has_one_translation() {
+        let mut words: Vec<&str> = ADJECTIVES
+            .iter()
+            .flat_map(|english| adjective(english).expect(english))
+            .collect();
+        words.extend(
+            FOOD_WORDS
+                .nouns
+                .iter()
+                .chain(SCIFI_WORDS.nouns)
+                .map(|english| noun(english).expect(english).0),
+        );
+        assert_eq!(words.iter().collect::<HashSet<_>>().len(), words.len());
+    }
+
+    #[test]
+    fn adjectives_agree_with_the_noun() {
+        let name = |adjective, noun| translate(NamePair { adjective, noun }).title_case();
+        assert_eq!(name("shiny", "apple"), "Glänzender Apfel");
+        assert_eq!(name("shiny", "mango"), "Glänzende Mango");
+        assert_eq!(name("shiny", "bread"), "Glänzendes Brot");
+        assert_eq!(name("quiet", "rocket"), "Leise Rakete");
+    }
+}
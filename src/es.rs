@@ -0,0 +1,752 @@
+//! Spanish word lists for [`Locale::Es`](crate::Locale::Es).
+//!
+//! The lists hold the English words that have a translation, so tags and filters work exactly as
+//! they do in English; [`translate`] swaps in the Spanish words when a name is rendered.
+
+use crate::{NamePair, WordLists};
+
+/// Shared adjectives with a gender-invariant Spanish translation, so they agree with any noun.
+pub(crate) const ADJECTIVES: &[&str] = &[
+    "agile",
+    "amber",
+    "autumn",
+    "azure",
+    "beige",
+    "blazing",
+    "blue",
+    "bold",
+    "brilliant",
+    "bubbly",
+    "calm",
+    "celestial",
+    "cheerful",
+    "clever",
+    "coral",
+    "crimson",
+    "crisp",
+    "dazzling",
+    "emerald",
+    "fierce",
+    "fragrant",
+    "friendly",
+    "gentle",
+    "gleaming",
+    "grand",
+    "gray",
+    "green",
+    "happy",
+    "huge",
+    "indigo",
+    "intrepid",
+    "ivory",
+    "jovial",
+    "keen",
+    "kindly",
+    "lavender",
+    "lively",
+    "magenta",
+    "mighty",
+    "noble",
+    "ochre",
+    "olive",
+    "optimistic",
+    "orange",
+    "pink",
+    "polar",
+    "primal",
+    "quick",
+    "radiant",
+    "roaring",
+    "sad",
+    "saffron",
+    "scarlet",
+    "sepia",
+    "sleek",
+    "smooth",
+    "sparkling",
+    "spicy",
+    "steadfast",
+    "sweet",
+    "tropical",
+    "turquoise",
+    "vibrant",
+    "violet",
+    "wild",
+    "wintry",
+    "witty",
+];
+
+/// Food words with a Spanish translation.
+pub(crate) const FOOD_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "acai",
+        "almond",
+        "anchovy",
+        "apple",
+        "apricot",
+        "artichoke",
+        "asparagus",
+        "avocado",
+        "bacon",
+        "banana",
+        "basil",
+        "beet",
+        "biscuit",
+        "blackberry",
+        "blueberry",
+        "bread",
+        "broccoli",
+        "bun",
+        "cabbage",
+        "cake",
+        "candy",
+        "cantaloupe",
+        "caramel",
+        "carrot",
+        "cashew",
+        "catfish",
+        "cauliflower",
+        "celery",
+        "cereal",
+        "chard",
+        "cherry",
+        "chicken",
+        "chipotle",
+        "churro",
+        "clams",
+        "coconut",
+        "cod",
+        "cookie",
+        "couscous",
+        "croissant",
+        "cucumber",
+        "currant",
+        "curry",
+        "date",
+        "doughnut",
+        "dragonfruit",
+        "duck",
+        "dumpling",
+        "durian",
+        "eel",
+        "eggplant",
+        "falafel",
+        "fennel",
+        "fig",
+        "flounder",
+        "garlic",
+        "ginger",
+        "granola",
+        "grape",
+        "grapefruit",
+        "grouper",
+        "guava",
+        "halibut",
+        "ham",
+        "hazelnut",
+        "herring",
+        "honey",
+        "jackfruit",
+        "jelly",
+        "jujube",
+        "kiwi",
+        "kumquat",
+        "lamb",
+        "lasagna",
+        "leek",
+        "lemon",
+        "lentil",
+        "lettuce",
+        "lime",
+        "lobster",
+        "loquat",
+        "lychee",
+        "mackerel",
+        "mandarin",
+        "mango",
+        "miso",
+        "muffin",
+        "mussels",
+        "mutton",
+        "nectarine",
+        "noodle",
+        "nutmeg",
+        "octopus",
+        "okra",
+        "omelet",
+        "onion",
+        "oyster",
+        "pancake",
+        "papaya",
+        "passionfruit",
+        "pasta",
+        "peach",
+        "peanut",
+        "pear",
+        "pepper",
+        "perch",
+        "persimmon",
+        "pie",
+        "pike",
+        "pineapple",
+        "pistachio",
+        "pizza",
+        "plantain",
+        "plum",
+        "pomegranate",
+        "pork",
+        "potato",
+        "prawn",
+        "prune",
+        "quinoa",
+        "radish",
+        "raisin",
+        "ramen",
+        "raspberry",
+        "salsa",
+        "sardine",
+        "sausage",
+        "scallop",
+        "sesame",
+        "shrimp",
+        "snapper",
+        "sole",
+        "sorbet",
+        "soy",
+        "spaghetti",
+        "spinach",
+        "squash",
+        "squid",
+        "starfruit",
+        "steak",
+        "stew",
+        "strawberry",
+        "sturgeon",
+        "sushi",
+        "taco",
+        "tamarind",
+        "tilapia",
+        "tomato",
+        "truffle",
+        "turbot",
+        "turkey",
+        "turnip",
+        "veal",
+        "venison",
+        "waffle",
+        "walnut",
+        "watermelon",
+        "yam",
+        "yogurt",
+        "zucchini",
+    ],
+};
+
+/// Sci-fi words with a Spanish translation.
+pub(crate) const SCIFI_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "android",
+        "anomaly",
+        "asteroid",
+        "astronaut",
+        "aurora",
+        "beacon",
+        "binary star",
+        "black hole",
+        "capsule",
+        "citadel",
+        "comet",
+        "constellation",
+        "cosmic dust",
+        "cosmos",
+        "cruiser",
+        "cyborg",
+        "dark matter",
+        "deep space",
+        "droid",
+        "dwarf planet",
+        "eclipse",
+        "engine",
+        "enigma",
+        "event horizon",
+        "exoplanet",
+        "frontier",
+        "galaxy",
+        "gas giant",
+        "gravity well",
+        "hyperdrive",
+        "ion storm",
+        "jetpack",
+        "laser cannon",
+        "light speed",
+        "lunar base",
+        "magnetar",
+        "meteor",
+        "meteor shower",
+        "meteorite",
+        "module",
+        "mothership",
+        "nebula",
+        "neutron",
+        "nova",
+        "observatory",
+        "orbiter",
+        "outpost",
+        "photon",
+        "pioneer",
+        "plasma",
+        "portal",
+        "probe",
+        "pulsar",
+        "quasar",
+        "reactor",
+        "red dwarf",
+        "red giant",
+        "rocket",
+        "satellite",
+        "scout",
+        "shuttle",
+        "singularity",
+        "solar flare",
+        "solar sail",
+        "solar wind",
+        "space probe",
+        "space station",
+        "star",
+        "star cluster",
+        "starship",
+        "station",
+        "supernova",
+        "thruster",
+        "transporter",
+        "white dwarf",
+        "wormhole",
+        "zenith",
+        "zodiac",
+        "hydrogen",
+        "helium",
+        "carbon",
+        "nitrogen",
+        "oxygen",
+        "neon",
+        "sodium",
+        "silicon",
+        "sulfur",
+        "calcium",
+        "titanium",
+        "iron",
+        "nickel",
+        "copper",
+        "zinc",
+        "silver",
+        "tin",
+        "platinum",
+        "gold",
+        "mercury",
+        "lead",
+        "uranium",
+        "plutonium",
+        "graphene",
+        "polymer",
+        "obsidian",
+        "quartz",
+        "diamond",
+        "sapphire",
+        "ruby",
+        "amethyst",
+        "topaz",
+        "jade",
+        "opal",
+        "ceramic",
+        "glass",
+        "alloy",
+    ],
+};
+
+/// Spanish for `pair`; words without a translation are kept.
+pub(crate) fn translate(pair: NamePair) -> NamePair {
+    NamePair {
+        adjective: word(pair.adjective).unwrap_or(pair.adjective),
+        noun: word(pair.noun).unwrap_or(pair.noun),
+    }
+}
+
+fn word(english: &str) -> Option<&'static str> {
+    Some(match english {
+        "agile" => "ágil",
+        "amber" => "ámbar",
+        "autumn" => "otoñal",
+        "azure" => "celeste",
+        "beige" => "beis",
+        "blazing" => "ardiente",
+        "blue" => "azul",
+        "bold" => "audaz",
+        "brilliant" => "brillante",
+        "bubbly" => "burbujeante",
+        "calm" => "apacible",
+        "celestial" => "celestial",
+        "cheerful" => "alegre",
+        "clever" => "inteligente",
+        "coral" => "coral",
+        "crimson" => "carmesí",
+        "crisp" => "crujiente",
+        "dazzling" => "deslumbrante",
+        "emerald" => "esmeralda",
+        "fierce" => "feroz",
+        "fragrant" => "fragante",
+        "friendly" => "amable",
+        "gentle" => "gentil",
+        "gleaming" => "reluciente",
+        "grand" => "grande",
+        "gray" => "gris",
+        "green" => "verde",
+        "happy" => "feliz",
+        "huge" => "enorme",
+        "indigo" => "índigo",
+        "intrepid" => "valiente",
+        "ivory" => "marfil",
+        "jovial" => "jovial",
+        "keen" => "perspicaz",
+        "kindly" => "cordial",
+        "lavender" => "lavanda",
+        "lively" => "vivaz",
+        "magenta" => "magenta",
+        "mighty" => "potente",
+        "noble" => "noble",
+        "ochre" => "ocre",
+        "olive" => "oliva",
+        "optimistic" => "optimista",
+        "orange" => "naranja",
+        "pink" => "rosa",
+        "polar" => "polar",
+        "primal" => "primordial",
+        "quick" => "veloz",
+        "radiant" => "radiante",
+        "roaring" => "rugiente",
+        "sad" => "triste",
+        "saffron" => "azafrán",
+        "scarlet" => "escarlata",
+        "sepia" => "sepia",
+        "sleek" => "elegante",
+        "smooth" => "suave",
+        "sparkling" => "centelleante",
+        "spicy" => "picante",
+        "steadfast" => "firme",
+        "sweet" => "dulce",
+        "tropical" => "tropical",
+        "turquoise" => "turquesa",
+        "vibrant" => "vibrante",
+        "violet" => "violeta",
+        "wild" => "salvaje",
+        "wintry" => "invernal",
+        "witty" => "ocurrente",
+        "acai" => "asaí",
+        "almond" => "almendra",
+        "anchovy" => "anchoa",
+        "apple" => "manzana",
+        "apricot" => "albaricoque",
+        "artichoke" => "alcachofa",
+        "asparagus" => "espárrago",
+        "avocado" => "aguacate",
+        "bacon" => "tocino",
+        "banana" => "plátano",
+        "basil" => "albahaca",
+        "beet" => "remolacha",
+        "biscuit" => "bizcocho",
+        "blackberry" => "mora",
+        "blueberry" => "arándano",
+        "bread" => "pan",
+        "broccoli" => "brócoli",
+        "bun" => "bollo",
+        "cabbage" => "repollo",
+        "cake" => "pastel",
+        "candy" => "golosina",
+        "cantaloupe" => "melón",
+        "caramel" => "caramelo",
+        "carrot" => "zanahoria",
+        "cashew" => "anacardo",
+        "catfish" => "bagre",
+        "cauliflower" => "coliflor",
+        "celery" => "apio",
+        "cereal" => "cereal",
+        "chard" => "acelga",
+        "cherry" => "cereza",
+        "chicken" => "pollo",
+        "chipotle" => "chipotle",
+        "churro" => "churro",
+        "clams" => "almejas",
+        "coconut" => "coco",
+        "cod" => "bacalao",
+        "cookie" => "galleta",
+        "couscous" => "cuscús",
+        "croissant" => "cruasán",
+        "cucumber" => "pepino",
+        "currant" => "grosella",
+        "curry" => "curry",
+        "date" => "dátil",
+        "doughnut" => "rosquilla",
+        "dragonfruit" => "pitaya",
+        "duck" => "pato",
+        "dumpling" => "empanadilla",
+        "durian" => "durián",
+        "eel" => "anguila",
+        "eggplant" => "berenjena",
+        "falafel" => "faláfel",
+        "fennel" => "hinojo",
+        "fig" => "higo",
+        "flounder" => "platija",
+        "garlic" => "ajo",
+        "ginger" => "jengibre",
+        "granola" => "granola",
+        "grape" => "uva",
+        "grapefruit" => "pomelo",
+        "grouper" => "mero",
+        "guava" => "guayaba",
+        "halibut" => "fletán",
+        "ham" => "jamón",
+        "hazelnut" => "avellana",
+        "herring" => "arenque",
+        "honey" => "miel",
+        "jackfruit" => "yaca",
+        "jelly" => "gelatina",
+        "jujube" => "azufaifa",
+        "kiwi" => "kiwi",
+        "kumquat" => "quinoto",
+        "lamb" => "cordero",
+        "lasagna" => "lasaña",
+        "leek" => "puerro",
+        "lemon" => "limón",
+        "lentil" => "lenteja",
+        "lettuce" => "lechuga",
+        "lime" => "lima",
+        "lobster" => "langosta",
+        "loquat" => "níspero",
+        "lychee" => "lichi",
+        "mackerel" => "caballa",
+        "mandarin" => "mandarina",
+        "mango" => "mango",
+        "miso" => "miso",
+        "muffin" => "magdalena",
+        "mussels" => "mejillones",
+        "mutton" => "carnero",
+        "nectarine" => "nectarina",
+        "noodle" => "fideo",
+        "nutmeg" => "nuez moscada",
+        "octopus" => "pulpo",
+        "okra" => "quimbombó",
+        "omelet" => "tortilla",
+        "onion" => "cebolla",
+        "oyster" => "ostra",
+        "pancake" => "panqueque",
+        "papaya" => "papaya",
+        "passionfruit" => "maracuyá",
+        "pasta" => "pasta",
+        "peach" => "melocotón",
+        "peanut" => "cacahuete",
+        "pear" => "pera",
+        "pepper" => "pimiento",
+        "perch" => "perca",
+        "persimmon" => "caqui",
+        "pie" => "tarta",
+        "pike" => "lucio",
+        "pineapple" => "piña",
+        "pistachio" => "pistacho",
+        "pizza" => "pizza",
+        "plantain" => "plátano macho",
+        "plum" => "ciruela",
+        "pomegranate" => "granada",
+        "pork" => "cerdo",
+        "potato" => "patata",
+        "prawn" => "gamba",
+        "prune" => "ciruela pasa",
+        "quinoa" => "quinoa",
+        "radish" => "rábano",
+        "raisin" => "pasa",
+        "ramen" => "ramen",
+        "raspberry" => "frambuesa",
+        "salsa" => "salsa",
+        "sardine" => "sardina",
+        "sausage" => "salchicha",
+        "scallop" => "vieira",
+        "sesame" => "sésamo",
+        "shrimp" => "camarón",
+        "snapper" => "pargo",
+        "sole" => "lenguado",
+        "sorbet" => "sorbete",
+        "soy" => "soja",
+        "spaghetti" => "espagueti",
+        "spinach" => "espinaca",
+        "squash" => "calabaza",
+        "squid" => "calamar",
+        "starfruit" => "carambola",
+        "steak" => "filete",
+        "stew" => "guiso",
+        "strawberry" => "fresa",
+        "sturgeon" => "esturión",
+        "sushi" => "sushi",
+        "taco" => "taco",
+        "tamarind" => "tamarindo",
+        "tilapia" => "tilapia",
+        "tomato" => "tomate",
+        "truffle" => "trufa",
+        "turbot" => "rodaballo",
+        "turkey" => "pavo",
+        "turnip" => "nabo",
+        "veal" => "ternera",
+        "venison" => "venado",
+        "waffle" => "gofre",
+        "walnut" => "nuez",
+        "watermelon" => "sandía",
+        "yam" => "ñame",
+        "yogurt" => "yogur",
+        "zucchini" => "calabacín",
+        "android" => "androide",
+        "anomaly" => "anomalía",
+        "asteroid" => "asteroide",
+        "astronaut" => "astronauta",
+        "aurora" => "aurora",
+        "beacon" => "baliza",
+        "binary star" => "estrella binaria",
+        "black hole" => "agujero negro",
+        "capsule" => "cápsula",
+        "citadel" => "ciudadela",
+        "comet" => "cometa",
+        "constellation" => "constelación",
+        "cosmic dust" => "polvo cósmico",
+        "cosmos" => "cosmos",
+        "cruiser" => "crucero",
+        "cyborg" => "cíborg",
+        "dark matter" => "materia oscura",
+        "deep space" => "espacio profundo",
+        "droid" => "droide",
+        "dwarf planet" => "planeta enano",
+        "eclipse" => "eclipse",
+        "engine" => "motor",
+        "enigma" => "enigma",
+        "event horizon" => "horizonte de sucesos",
+        "exoplanet" => "exoplaneta",
+        "frontier" => "frontera",
+        "galaxy" => "galaxia",
+        "gas giant" => "gigante gaseoso",
+        "gravity well" => "pozo gravitatorio",
+        "hyperdrive" => "hiperimpulsor",
+        "ion storm" => "tormenta iónica",
+        "jetpack" => "mochila cohete",
+        "laser cannon" => "cañón láser",
+        "light speed" => "velocidad de la luz",
+        "lunar base" => "base lunar",
+        "magnetar" => "magnetar",
+        "meteor" => "meteoro",
+        "meteor shower" => "lluvia de meteoros",
+        "meteorite" => "meteorito",
+        "module" => "módulo",
+        "mothership" => "nave nodriza",
+        "nebula" => "nebulosa",
+        "neutron" => "neutrón",
+        "nova" => "nova",
+        "observatory" => "observatorio",
+        "orbiter" => "orbitador",
+        "outpost" => "puesto avanzado",
+        "photon" => "fotón",
+        "pioneer" => "pionero",
+        "plasma" => "plasma",
+        "portal" => "portal",
+        "probe" => "sonda",
+        "pulsar" => "púlsar",
+        "quasar" => "cuásar",
+        "reactor" => "reactor",
+        "red dwarf" => "enana roja",
+        "red giant" => "gigante roja",
+        "rocket" => "cohete",
+        "satellite" => "satélite",
+        "scout" => "explorador",
+        "shuttle" => "lanzadera",
+        "singularity" => "singularidad",
+        "solar flare" => "llamarada solar",
+        "solar sail" => "vela solar",
+        "solar wind" => "viento solar",
+        "space probe" => "sonda espacial",
+        "space station" => "estación espacial",
+        "star" => "estrella",
+        "star cluster" => "cúmulo estelar",
+        "starship" => "nave estelar",
+        "station" => "estación",
+        "supernova" => "supernova",
+        "thruster" => "propulsor",
+        "transporter" => "teletransportador",
+        "white dwarf" => "enana blanca",
+        "wormhole" => "agujero de gusano",
+        "zenith" => "cenit",
+        "zodiac" => "zodiaco",
+        "hydrogen" => "hidrógeno",
+        "helium" => "helio",
+        "carbon" => "carbono",
+        "nitrogen" => "nitrógeno",
+        "oxygen" => "oxígeno",
+        "neon" => "neón",
+        "sodium" => "sodio",
+        "silicon" => "silicio",
+        "sulfur" => "azufre",
+        "calcium" => "calcio",
+        "titanium" => "titanio",
+        "iron" => "hierro",
+        "nickel" => "níquel",
+        "copper" => "cobre",
+        "zinc" => "zinc",
+        "silver" => "plata",
+        "tin" => "estaño",
+        "platinum" => "platino",
+        "gold" => "oro",
+        "mercury" => "mercurio",
+        "lead" => "plomo",
+        "uranium" => "uranio",
+        "plutonium" => "plutonio",
+        "graphene" => "grafeno",
+        "polymer" => "polímero",
+        "obsidian" => "obsidiana",
+        "quartz" => "cuarzo",
+        "diamond" => "diamante",
+        "sapphire" => "zafiro",
+        "ruby" => "rubí",
+        "amethyst" => "amatista",
+        "topaz" => "topacio",
+        "jade" => "jade",
+        "opal" => "ópalo",
+        "ceramic" => "cerámica",
+        "glass" => "vidrio",
+        "alloy" => "aleación",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Format, Locale};
+    use std::collections::HashSet;
+
+    #[test]
+    fn every_listed_word_has_one_translation() {
+        let words: Vec<&str> = ADJECTIVES
+            .iter()
+            .chain(FOOD_WORDS.nouns)
+            .chain(SCIFI_WORDS.nouns)
+            .map(|english| word(english).expect(english))
+            .collect();
+        assert_eq!(words.iter().collect::<HashSet<_>>().len(), words.len());
+    }
+
+    #[test]
+    fn names_are_rendered_in_spanish() {
+        let pair = translate(NamePair {
+            adjective: "brilliant",
+            noun: "black hole",
+        });
+        assert_eq!(
+            pair.render(Locale::Es.format(Format::default())),
+            "Agujero negro brillante"
+        );
+    }
+}
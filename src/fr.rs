@@ -0,0 +1,570 @@
+//! French word lists for [`Locale::Fr`](crate::Locale::Fr).
+//!
+//! The lists hold the English words that have a translation, so tags and filters work exactly as
+//! they do in English; [`translate`] swaps in the French words when a name is rendered.
+
+use crate::{NamePair, WordLists};
+
+/// Shared adjectives whose French translation is the same in the masculine and the feminine.
+pub(crate) const ADJECTIVES: &[&str] = &[
+    "acidic",
+    "agile",
+    "amber",
+    "arctic",
+    "aromatic",
+    "atomic",
+    "beige",
+    "brittle",
+    "calm",
+    "celestial",
+    "cheerful",
+    "clever",
+    "crimson",
+    "dynamic",
+    "electric",
+    "energetic",
+    "fearless",
+    "fierce",
+    "friendly",
+    "heroic",
+    "huge",
+    "indigo",
+    "intrepid",
+    "lavender",
+    "lucid",
+    "magenta",
+    "magnetic",
+    "melancholy",
+    "noble",
+    "ochre",
+    "optimistic",
+    "peaceful",
+    "pink",
+    "polar",
+    "pungent",
+    "quiet",
+    "rapid",
+    "red",
+    "sad",
+    "saffron",
+    "scarlet",
+    "sepia",
+    "shy",
+    "sincere",
+    "slender",
+    "solid",
+    "stellar",
+    "swift",
+    "tender",
+    "turquoise",
+    "vast",
+    "whimsical",
+    "wild",
+    "yellow",
+];
+
+/// Food words with a French translation.
+pub(crate) const FOOD_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "anchovy",
+        "apple",
+        "apricot",
+        "artichoke",
+        "asparagus",
+        "avocado",
+        "bacon",
+        "banana",
+        "basil",
+        "beet",
+        "biscuit",
+        "blackberry",
+        "blueberry",
+        "bread",
+        "broccoli",
+        "bun",
+        "cabbage",
+        "cake",
+        "candy",
+        "cantaloupe",
+        "caramel",
+        "carrot",
+        "cashew",
+        "cauliflower",
+        "celery",
+        "chard",
+        "cherry",
+        "chicken",
+        "clementine",
+        "coconut",
+        "cod",
+        "couscous",
+        "croissant",
+        "cucumber",
+        "curry",
+        "date",
+        "doughnut",
+        "duck",
+        "dumpling",
+        "eel",
+        "eggplant",
+        "fennel",
+        "fig",
+        "garlic",
+        "ginger",
+        "grape",
+        "grapefruit",
+        "ham",
+        "hazelnut",
+        "herring",
+        "honey",
+        "jelly",
+        "kiwi",
+        "lamb",
+        "lasagna",
+        "leek",
+        "lemon",
+        "lentil",
+        "lettuce",
+        "lime",
+        "lobster",
+        "mackerel",
+        "mandarin",
+        "mango",
+        "noodle",
+        "nutmeg",
+        "octopus",
+        "omelet",
+        "onion",
+        "oyster",
+        "pancake",
+        "papaya",
+        "peach",
+        "peanut",
+        "pear",
+        "pepper",
+        "pie",
+        "pike",
+        "pineapple",
+        "pistachio",
+        "pizza",
+        "plum",
+        "pomegranate",
+        "pork",
+        "potato",
+        "prawn",
+        "pretzel",
+        "prune",
+        "quinoa",
+        "radish",
+        "raisin",
+        "raspberry",
+        "sardine",
+        "sausage",
+        "sole",
+        "sorbet",
+        "soy",
+        "spinach",
+        "squash",
+        "squid",
+        "steak",
+        "stew",
+        "strawberry",
+        "sturgeon",
+        "sushi",
+        "tangerine",
+        "tomato",
+        "truffle",
+        "turbot",
+        "turkey",
+        "turnip",
+        "veal",
+        "venison",
+        "waffle",
+        "walnut",
+        "watermelon",
+        "yam",
+        "yogurt",
+        "zucchini",
+    ],
+};
+
+/// Sci-fi words with a French translation.
+pub(crate) const SCIFI_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "android",
+        "anomaly",
+        "asteroid",
+        "astronaut",
+        "aurora",
+        "beacon",
+        "black hole",
+        "capsule",
+        "citadel",
+        "comet",
+        "constellation",
+        "cosmos",
+        "cruiser",
+        "cyborg",
+        "dark matter",
+        "droid",
+        "dwarf planet",
+        "eclipse",
+        "engine",
+        "enigma",
+        "exoplanet",
+        "frontier",
+        "galaxy",
+        "gas giant",
+        "hyperdrive",
+        "lunar base",
+        "meteor",
+        "meteorite",
+        "module",
+        "mothership",
+        "nebula",
+        "nova",
+        "observatory",
+        "orbiter",
+        "outpost",
+        "photon",
+        "pioneer",
+        "plasma",
+        "portal",
+        "probe",
+        "pulsar",
+        "quasar",
+        "reactor",
+        "red giant",
+        "rocket",
+        "satellite",
+        "scout",
+        "shuttle",
+        "singularity",
+        "space station",
+        "star",
+        "starship",
+        "station",
+        "supernova",
+        "thruster",
+        "white dwarf",
+        "wormhole",
+        "zenith",
+        "zodiac",
+        "hydrogen",
+        "helium",
+        "carbon",
+        "oxygen",
+        "neon",
+        "titanium",
+        "iron",
+        "copper",
+        "silver",
+        "platinum",
+        "gold",
+        "uranium",
+        "graphene",
+        "obsidian",
+        "quartz",
+        "diamond",
+        "sapphire",
+        "glass",
+        "alloy",
+    ],
+};
+
+/// French for `pair`; words without a translation are kept.
+pub(crate) fn translate(pair: NamePair) -> NamePair {
+    NamePair {
+        adjective: word(pair.adjective).unwrap_or(pair.adjective),
+        noun: word(pair.noun).unwrap_or(pair.noun),
+    }
+}
+
+fn word(english: &str) -> Option<&'static str> {
+    Some(match english {
+        "acidic" => "acide",
+        "agile" => "agile",
+        "amber" => "ambre",
+        "arctic" => "arctique",
+        "aromatic" => "aromatique",
+        "atomic" => "atomique",
+        "beige" => "beige",
+        "brittle" => "fragile",
+        "calm" => "calme",
+        "celestial" => "céleste",
+        "cheerful" => "allègre",
+        "clever" => "habile",
+        "crimson" => "pourpre",
+        "dynamic" => "dynamique",
+        "electric" => "électrique",
+        "energetic" => "énergique",
+        "fearless" => "impavide",
+        "fierce" => "farouche",
+        "friendly" => "aimable",
+        "heroic" => "héroïque",
+        "huge" => "énorme",
+        "indigo" => "indigo",
+        "intrepid" => "intrépide",
+        "lavender" => "lavande",
+        "lucid" => "lucide",
+        "magenta" => "magenta",
+        "magnetic" => "magnétique",
+        "melancholy" => "mélancolique",
+        "noble" => "noble",
+        "ochre" => "ocre",
+        "optimistic" => "optimiste",
+        "peaceful" => "paisible",
+        "pink" => "rose",
+        "polar" => "polaire",
+        "pungent" => "âcre",
+        "quiet" => "tranquille",
+        "rapid" => "rapide",
+        "red" => "rouge",
+        "sad" => "triste",
+        "saffron" => "safran",
+        "scarlet" => "écarlate",
+        "sepia" => "sépia",
+        "shy" => "timide",
+        "sincere" => "sincère",
+        "slender" => "svelte",
+        "solid" => "solide",
+        "stellar" => "stellaire",
+        "swift" => "leste",
+        "tender" => "tendre",
+        "turquoise" => "turquoise",
+        "vast" => "vaste",
+        "whimsical" => "fantasque",
+        "wild" => "sauvage",
+        "yellow" => "jaune",
+        "anchovy" => "anchois",
+        "apple" => "pomme",
+        "apricot" => "abricot",
+        "artichoke" => "artichaut",
+        "asparagus" => "asperge",
+        "avocado" => "avocat",
+        "bacon" => "lardon",
+        "banana" => "banane",
+        "basil" => "basilic",
+        "beet" => "betterave",
+        "biscuit" => "biscuit",
+        "blackberry" => "mûre",
+        "blueberry" => "myrtille",
+        "bread" => "pain",
+        "broccoli" => "brocoli",
+        "bun" => "brioche",
+        "cabbage" => "chou",
+        "cake" => "gâteau",
+        "candy" => "bonbon",
+        "cantaloupe" => "melon",
+        "caramel" => "caramel",
+        "carrot" => "carotte",
+        "cashew" => "noix de cajou",
+        "cauliflower" => "chou-fleur",
+        "celery" => "céleri",
+        "chard" => "blette",
+        "cherry" => "cerise",
+        "chicken" => "poulet",
+        "clementine" => "clémentine",
+        "coconut" => "noix de coco",
+        "cod" => "cabillaud",
+        "couscous" => "couscous",
+        "croissant" => "croissant",
+        "cucumber" => "concombre",
+        "curry" => "curry",
+        "date" => "datte",
+        "doughnut" => "beignet",
+        "duck" => "canard",
+        "dumpling" => "raviole",
+        "eel" => "anguille",
+        "eggplant" => "aubergine",
+        "fennel" => "fenouil",
+        "fig" => "figue",
+        "garlic" => "ail",
+        "ginger" => "gingembre",
+        "grape" => "raisin",
+        "grapefruit" => "pamplemousse",
+        "ham" => "jambon",
+        "hazelnut" => "noisette",
+        "herring" => "hareng",
+        "honey" => "miel",
+        "jelly" => "gelée",
+        "kiwi" => "kiwi",
+        "lamb" => "agneau",
+        "lasagna" => "lasagne",
+        "leek" => "poireau",
+        "lemon" => "citron",
+        "lentil" => "lentille",
+        "lettuce" => "laitue",
+        "lime" => "citron vert",
+        "lobster" => "homard",
+        "mackerel" => "maquereau",
+        "mandarin" => "mandarine",
+        "mango" => "mangue",
+        "noodle" => "nouille",
+        "nutmeg" => "muscade",
+        "octopus" => "poulpe",
+        "omelet" => "omelette",
+        "onion" => "oignon",
+        "oyster" => "huître",
+        "pancake" => "crêpe",
+        "papaya" => "papaye",
+        "peach" => "pêche",
+        "peanut" => "cacahuète",
+        "pear" => "poire",
+        "pepper" => "poivron",
+        "pie" => "tarte",
+        "pike" => "brochet",
+        "pineapple" => "ananas",
+        "pistachio" => "pistache",
+        "pizza" => "pizza",
+        "plum" => "prune",
+        "pomegranate" => "grenade",
+        "pork" => "porc",
+        "potato" => "pomme de terre",
+        "prawn" => "crevette",
+        "pretzel" => "bretzel",
+        "prune" => "pruneau",
+        "quinoa" => "quinoa",
+        "radish" => "radis",
+        "raisin" => "raisin sec",
+        "raspberry" => "framboise",
+        "sardine" => "sardine",
+        "sausage" => "saucisse",
+        "sole" => "sole",
+        "sorbet" => "sorbet",
+        "soy" => "soja",
+        "spinach" => "épinard",
+        "squash" => "courge",
+        "squid" => "calmar",
+        "steak" => "bifteck",
+        "stew" => "ragoût",
+        "strawberry" => "fraise",
+        "sturgeon" => "esturgeon",
+        "sushi" => "sushi",
+        "tangerine" => "tangerine",
+        "tomato" => "tomate",
+        "truffle" => "truffe",
+        "turbot" => "turbot",
+        "turkey" => "dinde",
+        "turnip" => "navet",
+        "veal" => "veau",
+        "venison" => "chevreuil",
+        "waffle" => "gaufre",
+        "walnut" => "noix",
+        "watermelon" => "pastèque",
+        "yam" => "igname",
+        "yogurt" => "yaourt",
+        "zucchini" => "courgette",
+        "android" => "androïde",
+        "anomaly" => "anomalie",
+        "asteroid" => "astéroïde",
+        "astronaut" => "astronaute",
+        "aurora" => "aurore",
+        "beacon" => "balise",
+        "black hole" => "trou noir",
+        "capsule" => "capsule",
+        "citadel" => "citadelle",
+        "comet" => "comète",
+        "constellation" => "constellation",
+        "cosmos" => "cosmos",
+        "cruiser" => "croiseur",
+        "cyborg" => "cyborg",
+        "dark matter" => "matière noire",
+        "droid" => "droïde",
+        "dwarf planet" => "planète naine",
+        "eclipse" => "éclipse",
+        "engine" => "moteur",
+        "enigma" => "énigme",
+        "exoplanet" => "exoplanète",
+        "frontier" => "frontière",
+        "galaxy" => "galaxie",
+        "gas giant" => "géante gazeuse",
+        "hyperdrive" => "hyperpropulseur",
+        "lunar base" => "base lunaire",
+        "meteor" => "météore",
+        "meteorite" => "météorite",
+        "module" => "module",
+        "mothership" => "vaisseau mère",
+        "nebula" => "nébuleuse",
+        "nova" => "nova",
+        "observatory" => "observatoire",
+        "orbiter" => "orbiteur",
+        "outpost" => "avant-poste",
+        "photon" => "photon",
+        "pioneer" => "pionnier",
+        "plasma" => "plasma",
+        "portal" => "portail",
+        "probe" => "sonde",
+        "pulsar" => "pulsar",
+        "quasar" => "quasar",
+        "reactor" => "réacteur",
+        "red giant" => "géante rouge",
+        "rocket" => "fusée",
+        "satellite" => "satellite",
+        "scout" => "éclaireur",
+        "shuttle" => "navette",
+        "singularity" => "singularité",
+        "space station" => "station spatiale",
+        "star" => "étoile",
+        "starship" => "vaisseau spatial",
+        "station" => "station",
+        "supernova" => "supernova",
+        "thruster" => "propulseur",
+        "white dwarf" => "naine blanche",
+        "wormhole" => "trou de ver",
+        "zenith" => "zénith",
+        "zodiac" => "zodiaque",
+        "hydrogen" => "hydrogène",
+        "helium" => "hélium",
+        "carbon" => "carbone",
+        "oxygen" => "oxygène",
+        "neon" => "néon",
+        "titanium" => "titane",
+        "iron" => "fer",
+        "copper" => "cuivre",
+        "silver" => "argent",
+        "platinum" => "platine",
+        "gold" => "or",
+        "uranium" => "uranium",
+        "graphene" => "graphène",
+        "obsidian" => "obsidienne",
+        "quartz" => "quartz",
+        "diamond" => "diamant",
+        "sapphire" => "saphir",
+        "glass" => "verre",
+        "alloy" => "alliage",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Format, Locale};
+    use std::collections::HashSet;
+
+    #[test]
+    fn every_listed_word_has_one_translation() {
+        let words: Vec<&str> = ADJECTIVES
+            .iter()
+            .chain(FOOD_WORDS.nouns)
+            .chain(SCIFI_WORDS.nouns)
+            .map(|english| word(english).expect(english))
+            .collect();
+        assert_eq!(words.iter().collect::<HashSet<_>>().len(), words.len());
+    }
+
+    #[test]
+    fn names_are_rendered_in_french() {
+        let pair = translate(NamePair {
+            adjective: "calm",
+            noun: "apple",
+        });
+        assert_eq!(
+            pair.render(Locale::Fr.format(Format::default())),
+            "Pomme calme"
+        );
+    }
+}
@@ -0,0 +1,584 @@
+//! Japanese word lists for [`Locale::Ja`](crate::Locale::Ja).
+//!
+//! The lists hold the English words that have a translation, so tags and filters work exactly as
+//! they do in English; [`translate`] swaps in the Japanese words when a name is rendered.
+
+use crate::{NamePair, WordLists};
+
+/// Shared adjectives with a Japanese translation in attributive form (`輝く`, `静かな`).
+pub(crate) const ADJECTIVES: &[&str] = &[
+    "ancient",
+    "arctic",
+    "atomic",
+    "azure",
+    "black",
+    "blue",
+    "bright",
+    "calm",
+    "celestial",
+    "cheerful",
+    "clever",
+    "cold",
+    "crimson",
+    "crisp",
+    "dim",
+    "electric",
+    "fierce",
+    "fluffy",
+    "fresh",
+    "frosty",
+    "gentle",
+    "golden",
+    "green",
+    "happy",
+    "heroic",
+    "hot",
+    "huge",
+    "intrepid",
+    "ivory",
+    "juicy",
+    "lonely",
+    "magnetic",
+    "mighty",
+    "misty",
+    "new",
+    "noble",
+    "old",
+    "pink",
+    "playful",
+    "polar",
+    "pristine",
+    "proud",
+    "purple",
+    "quick",
+    "quiet",
+    "rainy",
+    "red",
+    "salty",
+    "shiny",
+    "shy",
+    "silly",
+    "silver",
+    "sleepy",
+    "smoky",
+    "snowy",
+    "sparkling",
+    "spicy",
+    "sticky",
+    "stormy",
+    "sunny",
+    "sweet",
+    "swift",
+    "tiny",
+    "tropical",
+    "twinkling",
+    "vivid",
+    "warm",
+    "white",
+    "wild",
+    "yellow",
+];
+
+/// Food words with a Japanese translation.
+pub(crate) const FOOD_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "almond",
+        "apple",
+        "apricot",
+        "avocado",
+        "bacon",
+        "banana",
+        "beef",
+        "blueberry",
+        "bread",
+        "cabbage",
+        "cake",
+        "candy",
+        "cantaloupe",
+        "caramel",
+        "carrot",
+        "cherry",
+        "chicken",
+        "coconut",
+        "cod",
+        "cookie",
+        "cucumber",
+        "curry",
+        "duck",
+        "dumpling",
+        "durian",
+        "edamame",
+        "eel",
+        "eggplant",
+        "fig",
+        "garlic",
+        "ginger",
+        "goji",
+        "grape",
+        "grapefruit",
+        "ham",
+        "honey",
+        "jelly",
+        "jujube",
+        "kimchi",
+        "kiwi",
+        "kumquat",
+        "lamb",
+        "leek",
+        "lemon",
+        "lime",
+        "lobster",
+        "loquat",
+        "lychee",
+        "mackerel",
+        "mandarin",
+        "mango",
+        "miso",
+        "mochi",
+        "muffin",
+        "noodle",
+        "octopus",
+        "omelet",
+        "onion",
+        "orange",
+        "oyster",
+        "pancake",
+        "papaya",
+        "peach",
+        "peanut",
+        "pear",
+        "persimmon",
+        "pineapple",
+        "pizza",
+        "pomegranate",
+        "pork",
+        "potato",
+        "radish",
+        "ramen",
+        "raspberry",
+        "sardine",
+        "sausage",
+        "scallion",
+        "sesame",
+        "shrimp",
+        "sorbet",
+        "soy",
+        "spinach",
+        "squash",
+        "squid",
+        "steak",
+        "stew",
+        "strawberry",
+        "sundae",
+        "sushi",
+        "tomato",
+        "truffle",
+        "turkey",
+        "turnip",
+        "waffle",
+        "walnut",
+        "watermelon",
+        "wintermelon",
+        "yam",
+        "yogurt",
+    ],
+};
+
+/// Sci-fi words with a Japanese translation.
+pub(crate) const SCIFI_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "android",
+        "anomaly",
+        "asteroid",
+        "astronaut",
+        "aurora",
+        "beacon",
+        "binary star",
+        "black hole",
+        "capsule",
+        "comet",
+        "constellation",
+        "cosmic dust",
+        "cosmos",
+        "cruiser",
+        "cyborg",
+        "dark matter",
+        "deep space",
+        "droid",
+        "dwarf planet",
+        "eclipse",
+        "engine",
+        "enigma",
+        "event horizon",
+        "exoplanet",
+        "frontier",
+        "galaxy",
+        "gas giant",
+        "gravity well",
+        "hyperdrive",
+        "ion storm",
+        "jetpack",
+        "laser cannon",
+        "light speed",
+        "lunar base",
+        "magnetar",
+        "meteor",
+        "meteorite",
+        "module",
+        "mothership",
+        "nebula",
+        "neutron",
+        "nova",
+        "observatory",
+        "orbiter",
+        "outpost",
+        "photon",
+        "pioneer",
+        "plasma",
+        "portal",
+        "probe",
+        "pulsar",
+        "quasar",
+        "reactor",
+        "red giant",
+        "rocket",
+        "satellite",
+        "scout",
+        "shuttle",
+        "singularity",
+        "solar flare",
+        "space station",
+        "star",
+        "star cluster",
+        "starship",
+        "station",
+        "supernova",
+        "thruster",
+        "transporter",
+        "white dwarf",
+        "wormhole",
+        "zenith",
+        "zodiac",
+        "hydrogen",
+        "helium",
+        "carbon",
+        "oxygen",
+        "neon",
+        "titanium",
+        "iron",
+        "copper",
+        "platinum",
+        "uranium",
+        "graphene",
+        "obsidian",
+        "quartz",
+        "diamond",
+        "sapphire",
+        "glass",
+        "alloy",
+    ],
+};
+
+/// Japanese for `pair`; words without a translation are kept.
+pub(crate) fn translate(pair: NamePair) -> NamePair {
+    NamePair {
+        adjective: word(pair.adjective).unwrap_or(pair.adjective),
+        noun: word(pair.noun).unwrap_or(pair.noun),
+    }
+}
+
+fn word(english: &str) -> Option<&'static str> {
+    Some(match english {
+        "ancient" => "古代の",
+        "arctic" => "北極の",
+        "atomic" => "原子の",
+        "azure" => "紺碧の",
+        "black" => "黒い",
+        "blue" => "青い",
+        "bright" => "明るい",
+        "calm" => "穏やかな",
+        "celestial" => "天空の",
+        "cheerful" => "陽気な",
+        "clever" => "賢い",
+        "cold" => "冷たい",
+        "crimson" => "深紅の",
+        "crisp" => "サクサクの",
+        "dim" => "薄暗い",
+        "electric" => "電気の",
+        "fierce" => "獰猛な",
+        "fluffy" => "ふわふわの",
+        "fresh" => "新鮮な",
+        "frosty" => "霜の",
+        "gentle" => "優しい",
+        "golden" => "金色の",
+        "green" => "緑の",
+        "happy" => "幸せな",
+        "heroic" => "英雄的な",
+        "hot" => "熱い",
+        "huge" => "巨大な",
+        "intrepid" => "勇敢な",
+        "ivory" => "象牙色の",
+        "juicy" => "ジューシーな",
+        "lonely" => "寂しい",
+        "magnetic" => "磁気の",
+        "mighty" => "力強い",
+        "misty" => "霧の",
+        "new" => "新しい",
+        "noble" => "高貴な",
+        "old" => "古い",
+        "pink" => "桃色の",
+        "playful" => "遊び好きな",
+        "polar" => "極地の",
+        "pristine" => "清らかな",
+        "proud" => "誇り高い",
+        "purple" => "紫の",
+        "quick" => "速い",
+        "quiet" => "静かな",
+        "rainy" => "雨の",
+        "red" => "赤い",
+        "salty" => "しょっぱい",
+        "shiny" => "輝く",
+        "shy" => "内気な",
+        "silly" => "おかしな",
+        "silver" => "銀色の",
+        "sleepy" => "眠い",
+        "smoky" => "煙たい",
+        "snowy" => "雪の",
+        "sparkling" => "きらきらの",
+        "spicy" => "辛い",
+        "sticky" => "ねばねばの",
+        "stormy" => "嵐の",
+        "sunny" => "晴れた",
+        "sweet" => "甘い",
+        "swift" => "素早い",
+        "tiny" => "小さな",
+        "tropical" => "熱帯の",
+        "twinkling" => "きらめく",
+        "vivid" => "鮮やかな",
+        "warm" => "温かい",
+        "white" => "白い",
+        "wild" => "野生の",
+        "yellow" => "黄色い",
+        "almond" => "アーモンド",
+        "apple" => "リンゴ",
+        "apricot" => "アンズ",
+        "avocado" => "アボカド",
+        "bacon" => "ベーコン",
+        "banana" => "バナナ",
+        "beef" => "牛肉",
+        "blueberry" => "ブルーベリー",
+        "bread" => "パン",
+        "cabbage" => "キャベツ",
+        "cake" => "ケーキ",
+        "candy" => "キャンディ",
+        "cantaloupe" => "メロン",
+        "caramel" => "キャラメル",
+        "carrot" => "ニンジン",
+        "cherry" => "サクランボ",
+        "chicken" => "チキン",
+        "coconut" => "ココナッツ",
+        "cod" => "タラ",
+        "cookie" => "クッキー",
+        "cucumber" => "キュウリ",
+        "curry" => "カレー",
+        "duck" => "アヒル",
+        "dumpling" => "餃子",
+        "durian" => "ドリアン",
+        "edamame" => "枝豆",
+        "eel" => "ウナギ",
+        "eggplant" => "ナス",
+        "fig" => "イチジク",
+        "garlic" => "ニンニク",
+        "ginger" => "ショウガ",
+        "goji" => "クコ",
+        "grape" => "ブドウ",
+        "grapefruit" => "グレープフルーツ",
+        "ham" => "ハム",
+        "honey" => "蜂蜜",
+        "jelly" => "ゼリー",
+        "jujube" => "ナツメ",
+        "kimchi" => "キムチ",
+        "kiwi" => "キウイ",
+        "kumquat" => "キンカン",
+        "lamb" => "ラム",
+        "leek" => "ポロネギ",
+        "lemon" => "レモン",
+        "lime" => "ライム",
+        "lobster" => "ロブスター",
+        "loquat" => "ビワ",
+        "lychee" => "ライチ",
+        "mackerel" => "サバ",
+        "mandarin" => "ミカン",
+        "mango" => "マンゴー",
+        "miso" => "味噌",
+        "mochi" => "餅",
+        "muffin" => "マフィン",
+        "noodle" => "麺",
+        "octopus" => "タコ",
+        "omelet" => "オムレツ",
+        "onion" => "タマネギ",
+        "orange" => "オレンジ",
+        "oyster" => "牡蠣",
+        "pancake" => "パンケーキ",
+        "papaya" => "パパイヤ",
+        "peach" => "モモ",
+        "peanut" => "ピーナッツ",
+        "pear" => "ナシ",
+        "persimmon" => "柿",
+        "pineapple" => "パイナップル",
+        "pizza" => "ピザ",
+        "pomegranate" => "ザクロ",
+        "pork" => "豚肉",
+        "potato" => "ジャガイモ",
+        "radish" => "ダイコン",
+        "ramen" => "ラーメン",
+        "raspberry" => "ラズベリー",
+        "sardine" => "イワシ",
+        "sausage" => "ソーセージ",
+        "scallion" => "ネギ",
+        "sesame" => "ゴマ",
+        "shrimp" => "エビ",
+        "sorbet" => "シャーベット",
+        "soy" => "大豆",
+        "spinach" => "ホウレンソウ",
+        "squash" => "カボチャ",
+        "squid" => "イカ",
+        "steak" => "ステーキ",
+        "stew" => "シチュー",
+        "strawberry" => "イチゴ",
+        "sundae" => "サンデー",
+        "sushi" => "寿司",
+        "tomato" => "トマト",
+        "truffle" => "トリュフ",
+        "turkey" => "七面鳥",
+        "turnip" => "カブ",
+        "waffle" => "ワッフル",
+        "walnut" => "クルミ",
+        "watermelon" => "スイカ",
+        "wintermelon" => "冬瓜",
+        "yam" => "山芋",
+        "yogurt" => "ヨーグルト",
+        "android" => "アンドロイド",
+        "anomaly" => "異常",
+        "asteroid" => "小惑星",
+        "astronaut" => "宇宙飛行士",
+        "aurora" => "オーロラ",
+        "beacon" => "ビーコン",
+        "binary star" => "連星",
+        "black hole" => "ブラックホール",
+        "capsule" => "カプセル",
+        "comet" => "彗星",
+        "constellation" => "星座",
+        "cosmic dust" => "宇宙塵",
+        "cosmos" => "宇宙",
+        "cruiser" => "巡洋艦",
+        "cyborg" => "サイボーグ",
+        "dark matter" => "暗黒物質",
+        "deep space" => "深宇宙",
+        "droid" => "ドロイド",
+        "dwarf planet" => "準惑星",
+        "eclipse" => "日食",
+        "engine" => "エンジン",
+        "enigma" => "謎",
+        "event horizon" => "事象の地平面",
+        "exoplanet" => "系外惑星",
+        "frontier" => "フロンティア",
+        "galaxy" => "銀河",
+        "gas giant" => "巨大ガス惑星",
+        "gravity well" => "重力井戸",
+        "hyperdrive" => "ハイパードライブ",
+        "ion storm" => "イオン嵐",
+        "jetpack" => "ジェットパック",
+        "laser cannon" => "レーザー砲",
+        "light speed" => "光速",
+        "lunar base" => "月面基地",
+        "magnetar" => "マグネター",
+        "meteor" => "流星",
+        "meteorite" => "隕石",
+        "module" => "モジュール",
+        "mothership" => "母船",
+        "nebula" => "星雲",
+        "neutron" => "中性子",
+        "nova" => "新星",
+        "observatory" => "天文台",
+        "orbiter" => "周回機",
+        "outpost" => "前哨基地",
+        "photon" => "光子",
+        "pioneer" => "開拓者",
+        "plasma" => "プラズマ",
+        "portal" => "ポータル",
+        "probe" => "探査機",
+        "pulsar" => "パルサー",
+        "quasar" => "クエーサー",
+        "reactor" => "原子炉",
+        "red giant" => "赤色巨星",
+        "rocket" => "ロケット",
+        "satellite" => "人工衛星",
+        "scout" => "偵察機",
+        "shuttle" => "シャトル",
+        "singularity" => "特異点",
+        "solar flare" => "太陽フレア",
+        "space station" => "宇宙ステーション",
+        "star" => "星",
+        "star cluster" => "星団",
+        "starship" => "宇宙船",
+        "station" => "ステーション",
+        "supernova" => "超新星",
+        "thruster" => "スラスター",
+        "transporter" => "転送装置",
+        "white dwarf" => "白色矮星",
+        "wormhole" => "ワームホール",
+        "zenith" => "天頂",
+        "zodiac" => "黄道帯",
+        "hydrogen" => "水素",
+        "helium" => "ヘリウム",
+        "carbon" => "炭素",
+        "oxygen" => "酸素",
+        "neon" => "ネオン",
+        "titanium" => "チタン",
+        "iron" => "鉄",
+        "copper" => "銅",
+        "platinum" => "プラチナ",
+        "uranium" => "ウラン",
+        "graphene" => "グラフェン",
+        "obsidian" => "黒曜石",
+        "quartz" => "石英",
+        "diamond" => "ダイヤモンド",
+        "sapphire" => "サファイア",
+        "glass" => "ガラス",
+        "alloy" => "合金",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Format, Locale};
+    use std::collections::HashSet;
+
+    #[test]
+    fn every_listed_word_has_one_translation() {
+        let words: Vec<&str> = ADJECTIVES
+            .iter()
+            .chain(FOOD_WORDS.nouns)
+            .chain(SCIFI_WORDS.nouns)
+            .map(|english| word(english).expect(english))
+            .collect();
+        assert_eq!(words.iter().collect::<HashSet<_>>().len(), words.len());
+    }
+
+    #[test]
+    fn names_are_rendered_in_japanese() {
+        let pair = translate(NamePair {
+            adjective: "shiny",
+            noun: "mango",
+        });
+        assert_eq!(
+            pair.render(Locale::Ja.format(Format::default())),
+            "輝くマンゴー"
+        );
+    }
+}
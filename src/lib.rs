@@ -1,23 +1,142 @@
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "de")]
+mod de;
+#[cfg(feature = "es")]
+mod es;
+#[cfg(feature = "fr")]
+mod fr;
+#[cfg(feature = "ja")]
+mod ja;
+
 use core::cell::RefCell;
+use core::fmt;
 use core::sync::atomic::{AtomicU64, Ordering};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash, Hasher, RandomState};
+use std::io::{self, BufRead};
+use std::net::IpAddr;
+use std::sync::OnceLock;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 thread_local! {
-    static GLOBAL_RNG: RefCell<TinyRng> = RefCell::new(TinyRng::seed_from_entropy());
+    static GLOBAL_RNG: RefCell<TinyRng> = RefCell::new(TinyRng::seed_from_entropy(RngVersion::V2));
 }
 
 static ENTROPY_COUNTER: AtomicU64 = AtomicU64::new(1);
 
 /// Randomly select an adjective + food word and return them in Title Case (e.g. `Shiny Mango`).
 pub fn random_food_name() -> String {
-    random_name(&FOOD_WORDS)
+    random_title(&FOOD_WORDS)
 }
 
 /// Randomly select an adjective + sci-fi word and return them in Title Case (e.g. `Nebulous Rocket`).
 pub fn random_scifi_name() -> String {
-    random_name(&SCIFI_WORDS)
+    random_title(&SCIFI_WORDS)
+}
+
+/// Randomly select an adjective + mineral word and return them in Title Case (e.g. `Polished Agate`).
+pub fn random_mineral_name() -> String {
+    random_title(&MINERAL_WORDS)
+}
+
+/// Randomly select an adjective + mythology word and return them in Title Case (e.g. `Fierce Kraken`).
+pub fn random_mythology_name() -> String {
+    random_title(&MYTHOLOGY_WORDS)
+}
+
+/// Randomly select an adjective + atompunk word and return them in Title Case (e.g. `Gleaming Ray Gun`).
+pub fn random_atompunk_name() -> String {
+    random_title(&ATOMPUNK_WORDS)
+}
+
+/// Randomly select an adjective + weather word and return them in Title Case (e.g. `Brisk Gale`).
+pub fn random_weather_name() -> String {
+    random_title(&WEATHER_WORDS)
+}
+
+/// Randomly select an adjective + music word and return them in Title Case (e.g. `Mellow Arpeggio`).
+pub fn random_music_name() -> String {
+    random_title(&MUSIC_WORDS)
+}
+
+/// Randomly select an adjective + occupation word and return them in Title Case (e.g. `Wistful Cartographer`).
+pub fn random_occupation_name() -> String {
+    random_title(&OCCUPATION_WORDS)
+}
+
+/// Randomly select an adjective + fantasy word and return them in Title Case (e.g. `Gilded Chalice`).
+pub fn random_fantasy_name() -> String {
+    random_title(&FANTASY_WORDS)
+}
+
+/// Randomly select an adjective + animal word and return them in Title Case (e.g. `Brisk Otter`).
+pub fn random_animal_name() -> String {
+    random_title(&ANIMAL_WORDS)
+}
+
+/// Randomly select an adjective + cyberpunk word and return them in Title Case (e.g. `Neon Netrunner`).
+pub fn random_cyberpunk_name() -> String {
+    random_title(&CYBERPUNK_WORDS)
+}
+
+/// Randomly select an adjective + celestial word and return them in Title Case (e.g. `Silent Blazar`).
+pub fn random_celestial_name() -> String {
+    random_title(&CELESTIAL_WORDS)
+}
+
+/// Randomly select an adjective + ocean word and return them in Title Case (e.g. `Drifting Kelp Forest`).
+pub fn random_ocean_name() -> String {
+    random_title(&OCEAN_WORDS)
+}
+
+/// Randomly select an adjective + chemical element and return them in Title Case
+/// (e.g. `Silent Iridium`).
+pub fn random_element_name() -> String {
+    random_title(&ELEMENT_WORDS)
+}
+
+/// Randomly select an adjective + arcade word and return them in Title Case (e.g. `Turbo Joystick`).
+pub fn random_arcade_name() -> String {
+    random_title(&ARCADE_WORDS)
+}
+
+/// Randomly select an adjective + herb or spice word and return them in Title Case (e.g. `Smoky Saffron`).
+pub fn random_herb_name() -> String {
+    random_title(&HERB_WORDS)
+}
+
+/// Randomly select an adjective + dessert word and return them in Title Case (e.g. `Velvet Macaron`).
+pub fn random_dessert_name() -> String {
+    random_title(&DESSERT_WORDS)
+}
+
+/// Randomly select an adjective + drink word and return them in Title Case (e.g. `Smoky Negroni`).
+pub fn random_drink_name() -> String {
+    random_title(&DRINK_WORDS)
+}
+
+/// Randomly select a sci-fi modifier + food word and return them in Title Case
+/// (e.g. `Quantum Quinoa`).
+pub fn random_hybrid_name() -> String {
+    random_title(&HYBRID_WORDS)
+}
+
+/// Randomly select a name from `theme` and return it in Title Case, for themes chosen at runtime.
+pub fn random_name(theme: Theme) -> String {
+    random_title(&theme.list())
+}
+
+/// [`random_name`] for a theme fixed at compile time (see [`theme_functions!`]).
+#[inline]
+pub fn random_name_of<T: StaticTheme>() -> String {
+    random_title(&const { T::THEME.list() })
+}
+
+/// Return the raw adjective + noun pair for `theme`.
+pub fn random_words(theme: Theme) -> NamePair {
+    random_pair(&theme.list())
 }
 
 /// Return the raw adjective + noun pair for the food generator.
@@ -30,197 +149,5822 @@ pub fn random_scifi_words() -> NamePair {
     random_pair(&SCIFI_WORDS)
 }
 
-/// Deterministic generator that can be seeded manually for reproducible output.
-#[derive(Clone)]
-pub struct NameGenerator {
-    rng: TinyRng,
+/// Return the raw adjective + noun pair for the mineral generator.
+pub fn random_mineral_words() -> NamePair {
+    random_pair(&MINERAL_WORDS)
 }
 
-impl NameGenerator {
-    /// Create a generator that is automatically seeded with best-effort entropy.
-    pub fn new() -> Self {
-        Self {
-            rng: TinyRng::seed_from_entropy(),
+/// Return the raw adjective + noun pair for the mythology generator.
+pub fn random_mythology_words() -> NamePair {
+    random_pair(&MYTHOLOGY_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the atompunk generator.
+pub fn random_atompunk_words() -> NamePair {
+    random_pair(&ATOMPUNK_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the weather generator.
+pub fn random_weather_words() -> NamePair {
+    random_pair(&WEATHER_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the music generator.
+pub fn random_music_words() -> NamePair {
+    random_pair(&MUSIC_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the occupation generator.
+pub fn random_occupation_words() -> NamePair {
+    random_pair(&OCCUPATION_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the fantasy generator.
+pub fn random_fantasy_words() -> NamePair {
+    random_pair(&FANTASY_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the animal generator.
+pub fn random_animal_words() -> NamePair {
+    random_pair(&ANIMAL_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the cyberpunk generator.
+pub fn random_cyberpunk_words() -> NamePair {
+    random_pair(&CYBERPUNK_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the celestial generator.
+pub fn random_celestial_words() -> NamePair {
+    random_pair(&CELESTIAL_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the ocean generator.
+pub fn random_ocean_words() -> NamePair {
+    random_pair(&OCEAN_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the element generator.
+pub fn random_element_words() -> NamePair {
+    random_pair(&ELEMENT_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the arcade generator.
+pub fn random_arcade_words() -> NamePair {
+    random_pair(&ARCADE_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the herb or spice generator.
+pub fn random_herb_words() -> NamePair {
+    random_pair(&HERB_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the dessert generator.
+pub fn random_dessert_words() -> NamePair {
+    random_pair(&DESSERT_WORDS)
+}
+
+/// Return the raw adjective + noun pair for the drink generator.
+pub fn random_drink_words() -> NamePair {
+    random_pair(&DRINK_WORDS)
+}
+
+/// Return the raw modifier + noun pair for the hybrid sci-fi food generator.
+pub fn random_hybrid_words() -> NamePair {
+    random_pair(&HYBRID_WORDS)
+}
+
+/// Return a food pair drawn from the caller's own RNG, for apps that already own a seeded RNG
+/// (e.g. `food_words_with(|| rng.next_u64())`); `next_u64` is called twice per pair.
+pub fn food_words_with(next_u64: impl FnMut() -> u64) -> NamePair {
+    words_with(Theme::Food, next_u64)
+}
+
+/// Return a sci-fi pair drawn from the caller's own RNG (see [`food_words_with`]).
+pub fn scifi_words_with(next_u64: impl FnMut() -> u64) -> NamePair {
+    words_with(Theme::SciFi, next_u64)
+}
+
+/// Return a pair from `theme` drawn from the caller's own RNG (see [`food_words_with`]).
+pub fn words_with(theme: Theme, mut next_u64: impl FnMut() -> u64) -> NamePair {
+    let list = theme.list();
+    let adjective = list.adjectives[(next_u64() % list.adjectives.len() as u64) as usize];
+    let noun = list.nouns[(next_u64() % list.nouns.len() as u64) as usize];
+    NamePair { adjective, noun }
+}
+
+/// Randomly generate a robot designation such as `XR-7 'Buttery Falcon'`.
+pub fn random_robot_designation() -> Designation {
+    GLOBAL_RNG.with(|rng| select_designation(&mut rng.borrow_mut()))
+}
+
+/// Map a name or key to a shard in `0..total_shards`.
+///
+/// The hash is fixed by this crate (it does not depend on the process, platform or Rust version),
+/// so the same key lands on the same shard in every CI run.
+///
+/// # Panics
+///
+/// Panics if `total_shards` is zero.
+pub fn shard_for(key: &str, total_shards: usize) -> usize {
+    assert!(total_shards > 0, "total_shards must be at least 1");
+    (stable_hash(key.as_bytes()) % total_shards as u64) as usize
+}
+
+/// Deterministic food name for the unordered pair `{a, b}`, e.g. a DM channel between two users.
+///
+/// `name_for_pair(a, b)` and `name_for_pair(b, a)` always agree, and like [`shard_for`] the
+/// result does not depend on the process, platform or Rust version.
+pub fn name_for_pair(a: &str, b: &str) -> String {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    // 0xff never occurs in UTF-8, so `("ab", "c")` and `("a", "bc")` hash differently.
+    let mut key = Vec::with_capacity(first.len() + second.len() + 1);
+    key.extend_from_slice(first.as_bytes());
+    key.push(0xff);
+    key.extend_from_slice(second.as_bytes());
+    hashed_pair(&FOOD_WORDS, stable_hash(&key)).title_case()
+}
+
+/// `steps` food names morphing from the first name of [`NameGenerator::from_seed`]`(seed_a)` to
+/// the first name of `from_seed(seed_b)`, e.g. for smooth transitions in generative art.
+///
+/// The adjective and noun indices are walked linearly and independently through the word lists,
+/// so consecutive names share words where the lists allow; the result is fully deterministic.
+pub fn interpolate(seed_a: u64, seed_b: u64, steps: usize) -> Vec<String> {
+    let indices = |seed| {
+        let mut rng = TinyRng::from_seed(seed);
+        let adjective = rng.index(FOOD_WORDS.adjectives.len());
+        (adjective, rng.index(FOOD_WORDS.nouns.len()))
+    };
+    let (start, end) = (indices(seed_a), indices(seed_b));
+    let lerp = |from: usize, to: usize, step: usize| {
+        let t = step as f64 / (steps - 1).max(1) as f64;
+        (from as f64 + (to as f64 - from as f64) * t).round() as usize
+    };
+    (0..steps)
+        .map(|step| {
+            NamePair {
+                adjective: FOOD_WORDS.adjectives[lerp(start.0, end.0, step)],
+                noun: FOOD_WORDS.nouns[lerp(start.1, end.1, step)],
+            }
+            .title_case()
+        })
+        .collect()
+}
+
+/// Stable food name for an IP address, for labelling devices consistently across tools.
+///
+/// IPv4-mapped IPv6 addresses (`::ffff:10.0.0.1`) get the same name as the IPv4 address.
+pub fn name_for_ip(ip: IpAddr) -> String {
+    let hash = match ip.to_canonical() {
+        IpAddr::V4(ip) => stable_hash(&ip.octets()),
+        IpAddr::V6(ip) => stable_hash(&ip.octets()),
+    };
+    hashed_pair(&FOOD_WORDS, hash).title_case()
+}
+
+/// Stable food name for a MAC address.
+pub fn name_for_mac(mac: [u8; 6]) -> String {
+    hashed_pair(&FOOD_WORDS, stable_hash(&mac)).title_case()
+}
+
+/// Health of a monitored thing, for status-flavored names from [`name_for_status`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Status {
+    Healthy,
+    Recovering,
+    Warning,
+    Critical,
+    Unknown,
+}
+
+impl Status {
+    /// Every status, healthiest first.
+    pub const ALL: [Status; 5] = [
+        Self::Healthy,
+        Self::Recovering,
+        Self::Warning,
+        Self::Critical,
+        Self::Unknown,
+    ];
+
+    /// Lowercase name (e.g. `critical`).
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::Recovering => "recovering",
+            Self::Warning => "warning",
+            Self::Critical => "critical",
+            Self::Unknown => "unknown",
         }
     }
 
-    /// Create a generator from a fixed 64-bit seed.
-    pub fn from_seed(seed: u64) -> Self {
-        Self {
-            rng: TinyRng::from_seed(seed),
+    /// Adjectives that read as this status; a subset of the shared adjectives.
+    pub const fn adjectives(self) -> &'static [&'static str] {
+        match self {
+            Self::Healthy => &[
+                "balanced",
+                "bright",
+                "calm",
+                "cheerful",
+                "cloudless",
+                "content",
+                "gentle",
+                "mellow",
+                "peaceful",
+                "pristine",
+                "radiant",
+                "serene",
+                "steadfast",
+                "sunny",
+            ],
+            Self::Recovering => &[
+                "brisk",
+                "eager",
+                "fresh",
+                "hopeful",
+                "lively",
+                "optimistic",
+                "plucky",
+                "spirited",
+            ],
+            Self::Warning => &[
+                "anxious", "cranky", "grumpy", "jittery", "nervous", "restless", "tense", "worried",
+            ],
+            Self::Critical => &[
+                "angry",
+                "blazing",
+                "crimson",
+                "fierce",
+                "fiery",
+                "roaring",
+                "scalding",
+                "scarlet",
+                "smoky",
+                "stormy",
+                "sweltering",
+                "thundering",
+            ],
+            Self::Unknown => &[
+                "dim", "dusky", "foggy", "misty", "quiet", "shadowy", "silent",
+            ],
         }
     }
+}
 
-    /// Get a food-themed adjective + noun pair.
-    pub fn food_words(&mut self) -> NamePair {
-        select_pair(&FOOD_WORDS, &mut self.rng)
-    }
+/// Status-flavored sci-fi name for `key`, e.g. `Blazing Reactor` for a failing node.
+///
+/// The noun depends only on `key`, so a node keeps its noun while the adjective follows its
+/// status; both are deterministic like [`shard_for`].
+pub fn name_for_status(key: &str, status: Status) -> String {
+    let hash = stable_hash(key.as_bytes());
+    let adjectives = status.adjectives();
+    let adjective = adjectives[((hash >> 32) % adjectives.len() as u64) as usize];
+    let noun = SCIFI_WORDS.nouns[(hash % SCIFI_WORDS.nouns.len() as u64) as usize];
+    NamePair { adjective, noun }.title_case()
+}
 
-    /// Get a sci-fi-themed adjective + noun pair.
-    pub fn scifi_words(&mut self) -> NamePair {
-        select_pair(&SCIFI_WORDS, &mut self.rng)
+/// Length of the time buckets named by [`name_for_time`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Granularity {
+    Hour,
+    Day,
+    /// Weeks start on Monday, 00:00 UTC.
+    Week,
+}
+
+impl Granularity {
+    const fn seconds(self) -> i64 {
+        match self {
+            Self::Hour => 60 * 60,
+            Self::Day => 24 * 60 * 60,
+            Self::Week => 7 * 24 * 60 * 60,
+        }
     }
+}
 
-    /// Convenience helper that returns a formatted food name (Title Case with a space).
-    pub fn food_name(&mut self) -> String {
-        self.food_words().title_case()
+/// Deterministic food name for the UTC hour, day or week containing `time`, so every service
+/// calls the same bucket e.g. "the Sunny Mango hour".
+///
+/// Each granularity names its buckets independently: the hour and the day containing `time` get
+/// unrelated names.
+pub fn name_for_time(time: SystemTime, granularity: Granularity) -> String {
+    let seconds = match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_secs() as i64,
+        Err(before) => -(before.duration().as_secs_f64().ceil() as i64),
+    };
+    // The epoch was a Thursday; shift so weeks start on Monday.
+    let offset = match granularity {
+        Granularity::Week => 3 * Granularity::Day.seconds(),
+        _ => 0,
+    };
+    let bucket = (seconds + offset).div_euclid(granularity.seconds());
+    let mut key = [0; 9];
+    key[0] = granularity as u8;
+    key[1..].copy_from_slice(&bucket.to_le_bytes());
+    hashed_pair(&FOOD_WORDS, stable_hash(&key)).title_case()
+}
+
+/// Bits of a grid cell encoded by the adjective of each [`name_for_coords`] pair.
+const GEO_ADJECTIVE_BITS: u32 = ADJECTIVES.len().ilog2();
+/// Bits of a grid cell encoded by the noun of each [`name_for_coords`] pair.
+const GEO_NOUN_BITS: u32 = FOOD_WORDS.nouns.len().ilog2();
+const GEO_PAIR_BITS: u32 = GEO_ADJECTIVE_BITS + GEO_NOUN_BITS;
+const GEO_FORMAT: Format<'static> = Format::new(Case::Lower, "-");
+
+/// Most pairs a [`name_for_coords`] location name may have.
+pub const MAX_COORD_PRECISION: usize = 4;
+
+/// Name the grid cell containing `lat`/`lon` with `precision` food pairs joined by dots, e.g.
+/// `golden-lobster.rapid-lobster`, for playful location sharing.
+///
+/// Like a geohash, each extra pair narrows the cell (roughly 150 km, 1 km, 5 m and 4 cm across
+/// for one to four pairs) and nearby places tend to share a prefix. [`coords_for_name`] reverses
+/// the mapping. Cells are stable for a given crate version.
+///
+/// Latitudes outside `-90..=90` and longitudes outside `-180..=180` are clamped.
+///
+/// # Panics
+///
+/// Panics if `precision` is zero or greater than [`MAX_COORD_PRECISION`].
+pub fn name_for_coords(lat: f64, lon: f64, precision: usize) -> String {
+    assert!(
+        (1..=MAX_COORD_PRECISION).contains(&precision),
+        "precision must be between 1 and {MAX_COORD_PRECISION}"
+    );
+    let bits = precision as u32 * GEO_PAIR_BITS;
+    let (mut lon_bits, mut lat_bits) = (bits - bits / 2, bits / 2);
+    let lon = grid_index((lon + 180.0) / 360.0, lon_bits);
+    let lat = grid_index((lat + 90.0) / 180.0, lat_bits);
+    // Interleave starting with longitude, as geohashes do.
+    let mut cell = 0u64;
+    for position in 0..bits {
+        let bit = if position % 2 == 0 {
+            lon_bits -= 1;
+            lon >> lon_bits & 1
+        } else {
+            lat_bits -= 1;
+            lat >> lat_bits & 1
+        };
+        cell = cell << 1 | bit;
     }
 
-    /// Convenience helper that returns a formatted sci-fi name (Title Case with a space).
-    pub fn scifi_name(&mut self) -> String {
-        self.scifi_words().title_case()
+    let mut name = String::new();
+    for pair in (0..precision as u32).rev() {
+        let chunk = cell >> (pair * GEO_PAIR_BITS);
+        let adjective =
+            ADJECTIVES[(chunk >> GEO_NOUN_BITS & low_bits(GEO_ADJECTIVE_BITS)) as usize];
+        let noun = FOOD_WORDS.nouns[(chunk & low_bits(GEO_NOUN_BITS)) as usize];
+        if !name.is_empty() {
+            name.push('.');
+        }
+        name.push_str(&NamePair { adjective, noun }.render(GEO_FORMAT));
     }
+    name
 }
 
-impl Default for NameGenerator {
-    fn default() -> Self {
-        Self::new()
+/// Centre (`(lat, lon)`) of the grid cell named by a [`name_for_coords`] name, or `None` if
+/// `name` is not a location name.
+pub fn coords_for_name(name: &str) -> Option<(f64, f64)> {
+    let pairs: Vec<&str> = name.split('.').collect();
+    if pairs.len() > MAX_COORD_PRECISION {
+        return None;
     }
+    let mut cell = 0u64;
+    for pair in &pairs {
+        let pair = NamePair::parse(pair)?;
+        let adjective = ADJECTIVES.iter().position(|word| *word == pair.adjective)? as u64;
+        let noun = FOOD_WORDS
+            .nouns
+            .iter()
+            .position(|word| *word == pair.noun)? as u64;
+        if adjective > low_bits(GEO_ADJECTIVE_BITS) || noun > low_bits(GEO_NOUN_BITS) {
+            return None;
+        }
+        cell = cell << GEO_PAIR_BITS | adjective << GEO_NOUN_BITS | noun;
+    }
+
+    let bits = pairs.len() as u32 * GEO_PAIR_BITS;
+    let (lon_bits, lat_bits) = (bits - bits / 2, bits / 2);
+    let (mut lon, mut lat) = (0u64, 0u64);
+    for position in 0..bits {
+        let bit = cell >> (bits - 1 - position) & 1;
+        if position % 2 == 0 {
+            lon = lon << 1 | bit;
+        } else {
+            lat = lat << 1 | bit;
+        }
+    }
+    let centre = |index: u64, bits: u32| (index as f64 + 0.5) / (1u64 << bits) as f64;
+    Some((
+        centre(lat, lat_bits) * 180.0 - 90.0,
+        centre(lon, lon_bits) * 360.0 - 180.0,
+    ))
 }
 
-/// Raw adjective + noun pair.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct NamePair {
-    pub adjective: &'static str,
-    pub noun: &'static str,
+/// Index of the `bits`-bit grid cell containing `fraction` of the way along an axis.
+fn grid_index(fraction: f64, bits: u32) -> u64 {
+    let cells = 1u64 << bits;
+    ((fraction.clamp(0.0, 1.0) * cells as f64) as u64).min(cells - 1)
 }
 
-impl NamePair {
-    /// Render the pair as `Titlecase Titlecase`.
-    pub fn title_case(&self) -> String {
-        let mut text = String::with_capacity(self.adjective.len() + self.noun.len() + 1);
-        push_title_case(self.adjective, &mut text);
-        text.push(' ');
-        push_title_case(self.noun, &mut text);
-        text
-    }
+const fn low_bits(bits: u32) -> u64 {
+    (1 << bits) - 1
 }
 
-fn random_name(list: &WordLists) -> String {
-    random_pair(list).title_case()
+/// Drop every name in `names` that already exists in `corpus`, or earlier in `names` itself.
+///
+/// Names are compared case- and format-insensitively, so `Shiny Mango`, `shiny-mango`,
+/// `shiny_mango` and `ShinyMango` all count as the same name. Kept names are returned unchanged
+/// and in their original order.
+pub fn dedup_against<N, C>(names: N, corpus: C) -> Vec<String>
+where
+    N: IntoIterator<Item = String>,
+    C: IntoIterator<Item = String>,
+{
+    let mut seen: HashSet<String> = corpus
+        .into_iter()
+        .map(|name| comparison_key(&name))
+        .collect();
+    names
+        .into_iter()
+        .filter(|name| seen.insert(comparison_key(name)))
+        .collect()
 }
 
-fn random_pair(list: &WordLists) -> NamePair {
-    GLOBAL_RNG.with(|rng| select_pair(list, &mut *rng.borrow_mut()))
+/// Every built-in noun indexed by its [`comparison_key`].
+fn noun_keys() -> &'static HashMap<String, &'static str> {
+    static NOUN_KEYS: OnceLock<HashMap<String, &'static str>> = OnceLock::new();
+    NOUN_KEYS.get_or_init(|| {
+        THEMES
+            .iter()
+            .flat_map(|(_, list)| list.nouns.iter())
+            .map(|noun| (comparison_key(noun), *noun))
+            .collect()
+    })
 }
 
-fn select_pair(words: &WordLists, rng: &mut TinyRng) -> NamePair {
-    let adjective = ADJECTIVES[rng.index(ADJECTIVES.len())];
-    let noun = words.nouns[rng.index(words.nouns.len())];
-    NamePair { adjective, noun }
+/// No two distinct pairs may render to the same name in any [`Format`]; formatted names are used
+/// as primary keys.
+const _: () = assert!(
+    pairs_are_distinguishable(ADJECTIVES, &THEME_NOUNS),
+    "two distinct word pairs share a comparison key (or ADJECTIVES is not sorted)"
+);
+const _: () = assert!(
+    pairs_are_distinguishable(SCIFI_MODIFIERS, &[HYBRID_WORDS.nouns]),
+    "two distinct hybrid pairs share a comparison key (or SCIFI_MODIFIERS is not sorted)"
+);
+/// The compile-time checks lowercase ASCII only, so they see built-in words exactly as
+/// [`comparison_key`] does.
+const _: () = assert!(
+    all_ascii(&[ADJECTIVES, SCIFI_MODIFIERS]) && all_ascii(&THEME_NOUNS),
+    "built-in words must be ASCII"
+);
+
+/// Noun lists of [`THEMES`], for [`pairs_are_distinguishable`].
+const THEME_NOUNS: [&[&str]; THEMES.len()] = {
+    let mut nouns: [&[&str]; THEMES.len()] = [&[]; THEMES.len()];
+    let mut index = 0;
+    while index < THEMES.len() {
+        nouns[index] = THEMES[index].1.nouns;
+        index += 1;
+    }
+    nouns
+};
+
+const fn all_ascii(lists: &[&[&str]]) -> bool {
+    let mut list = 0;
+    while list < lists.len() {
+        let mut index = 0;
+        while index < lists[list].len() {
+            if !lists[list][index].is_ascii() {
+                return false;
+            }
+            index += 1;
+        }
+        list += 1;
+    }
+    true
 }
 
-fn push_title_case(word: &str, buf: &mut String) {
-    let mut capitalize_next = true;
-    for ch in word.chars() {
-        if ch == '-' || ch == '_' || ch == ' ' {
-            buf.push(' ');
-            capitalize_next = true;
-            continue;
+/// Whether every distinct adjective + noun pair drawn from `adjectives` and the `nouns` lists has
+/// its own [`comparison_key`], i.e. still differs once case, separators and punctuation are
+/// dropped.
+///
+/// Words must be distinct on their own, and no adjective that extends another may shift letters
+/// across the join (`gold` + `enigma` against `golden` + `igma`). `adjectives` must be sorted by
+/// key, which puts every adjective that extends another right after it.
+///
+/// Only ASCII letters are lowercased here, so words must be ASCII or already comparison keys.
+const fn pairs_are_distinguishable(adjectives: &[&str], nouns: &[&[&str]]) -> bool {
+    let lists = nouns;
+    let Some(nouns) = NounKeys::build(lists) else {
+        return false;
+    };
+    let mut first = 0;
+    while first < adjectives.len() {
+        let shorter = adjectives[first].as_bytes();
+        let mut second = first + 1;
+        while second < adjectives.len() {
+            let longer = adjectives[second].as_bytes();
+            if !key_less_than(shorter, longer) {
+                return false;
+            }
+            let Some(rest) = strip_key_prefix(longer, 0, shorter, 0) else {
+                break;
+            };
+            if shifts_across_join(longer, rest, lists, &nouns) {
+                return false;
+            }
+            second += 1;
         }
-        if capitalize_next {
-            for upper in ch.to_uppercase() {
-                buf.push(upper);
+        first += 1;
+    }
+    true
+}
+
+/// Most distinct nouns a theme set may have, bounded by the size of [`NounKeys`].
+const MAX_NOUNS: usize = SLOTS / 2 - 1;
+
+/// Compile-time hash set of noun keys.
+struct NounKeys<'a> {
+    slots: [Option<&'a str>; SLOTS],
+}
+
+/// Slots of a [`NounKeys`].
+const SLOTS: usize = 4096;
+
+impl<'a> NounKeys<'a> {
+    /// Collect every noun of `lists`, or `None` if two different nouns share a key.
+    const fn build(lists: &[&[&'a str]]) -> Option<NounKeys<'a>> {
+        let mut keys = NounKeys {
+            slots: [None; SLOTS],
+        };
+        let mut filled = 0;
+        let mut theme = 0;
+        while theme < lists.len() {
+            let nouns = lists[theme];
+            let mut index = 0;
+            while index < nouns.len() {
+                let noun = nouns[index];
+                let slot = keys.find(noun.as_bytes(), 0);
+                match keys.slots[slot] {
+                    None => {
+                        keys.slots[slot] = Some(noun);
+                        filled += 1;
+                        assert!(filled <= MAX_NOUNS, "too many nouns for NounKeys");
+                    }
+                    Some(other) if !same_bytes(other.as_bytes(), noun.as_bytes()) => return None,
+                    Some(_) => {}
+                }
+                index += 1;
             }
-            capitalize_next = false;
-        } else {
-            for lower in ch.to_lowercase() {
-                buf.push(lower);
+            theme += 1;
+        }
+        Some(keys)
+    }
+
+    /// Whether the key of `word[from..]` is the key of a collected noun.
+    const fn contains(&self, word: &[u8], from: usize) -> bool {
+        self.slots[self.find(word, from)].is_some()
+    }
+
+    /// The slot holding the key of `word[from..]`, or the empty slot where it would go.
+    const fn find(&self, word: &[u8], from: usize) -> usize {
+        let mut slot = key_hash(word, from) as usize % SLOTS;
+        while let Some(noun) = self.slots[slot] {
+            let noun = noun.as_bytes();
+            if let Some(rest) = strip_key_prefix(noun, 0, word, from)
+                && key_is_empty(noun, rest)
+            {
+                break;
             }
+            slot = (slot + 1) % SLOTS;
         }
+        slot
     }
 }
 
-#[derive(Clone, Copy)]
-struct TinyRng {
-    state: u64,
+/// Whether some noun starts with the key of `adjective[from..]` and continues as another noun.
+const fn shifts_across_join(
+    adjective: &[u8],
+    from: usize,
+    lists: &[&[&str]],
+    nouns: &NounKeys,
+) -> bool {
+    let mut theme = 0;
+    while theme < lists.len() {
+        let list = lists[theme];
+        let mut index = 0;
+        while index < list.len() {
+            let noun = list[index].as_bytes();
+            if let Some(rest) = strip_key_prefix(noun, 0, adjective, from)
+                && nouns.contains(noun, rest)
+            {
+                return true;
+            }
+            index += 1;
+        }
+        theme += 1;
+    }
+    false
 }
 
-impl TinyRng {
-    fn seed_from_entropy() -> Self {
-        let time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map(|d| d.as_nanos() as u64)
-            .unwrap_or(0);
-        let extra = ENTROPY_COUNTER.fetch_add(0x9E37, Ordering::Relaxed);
-        Self::from_seed(time ^ extra ^ extra.rotate_left(32))
+/// Whether the key of `one` sorts strictly before the key of `other`.
+const fn key_less_than(one: &[u8], other: &[u8]) -> bool {
+    let (mut at_one, mut at_other) = (0, 0);
+    loop {
+        match (next_key_byte(one, at_one), next_key_byte(other, at_other)) {
+            (_, None) => return false,
+            (None, Some(_)) => return true,
+            (Some((left, next_one)), Some((right, next_other))) => {
+                if left != right {
+                    return left < right;
+                }
+                (at_one, at_other) = (next_one, next_other);
+            }
+        }
     }
+}
 
-    fn from_seed(seed: u64) -> Self {
-        let state = if seed == 0 { 0x4d595df4d0f33173 } else { seed };
-        Self { state }
+/// FNV-1a over the key of `word[from..]`.
+const fn key_hash(word: &[u8], from: usize) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    let mut at = from;
+    while let Some((byte, next)) = next_key_byte(word, at) {
+        hash = (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        at = next;
     }
+    hash
+}
 
-    fn next_u64(&mut self) -> u64 {
-        let mut x = self.state;
-        x ^= x >> 12;
-        x ^= x << 25;
-        x ^= x >> 27;
-        self.state = x;
-        x.wrapping_mul(0x2545F4914F6CDD1D)
+/// If the key of `word[at..]` starts with the key of `prefix[from..]`, the index in `word` just
+/// past that shared prefix.
+const fn strip_key_prefix(word: &[u8], at: usize, prefix: &[u8], from: usize) -> Option<usize> {
+    let (mut at, mut from) = (at, from);
+    loop {
+        let Some((expected, next)) = next_key_byte(prefix, from) else {
+            return Some(at);
+        };
+        let Some((actual, after)) = next_key_byte(word, at) else {
+            return None;
+        };
+        if actual != expected {
+            return None;
+        }
+        (at, from) = (after, next);
     }
+}
 
-    fn index(&mut self, upper: usize) -> usize {
-        let bound = upper as u64;
-        if bound == 0 {
-            return 0;
+const fn key_is_empty(word: &[u8], at: usize) -> bool {
+    next_key_byte(word, at).is_none()
+}
+
+/// The next byte of `word[at..]` that [`comparison_key`] keeps, lowercased, and the index after it.
+const fn next_key_byte(word: &[u8], at: usize) -> Option<(u8, usize)> {
+    let mut at = at;
+    while at < word.len() {
+        let byte = word[at];
+        at += 1;
+        if byte.is_ascii_alphanumeric() || !byte.is_ascii() {
+            return Some((byte.to_ascii_lowercase(), at));
         }
-        (self.next_u64() % bound) as usize
     }
+    None
 }
 
-struct WordLists {
-    nouns: &'static [&'static str],
+const fn same_bytes(one: &[u8], other: &[u8]) -> bool {
+    if one.len() != other.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < one.len() {
+        if one[index] != other[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
 }
 
-const ADJECTIVES: &[&str] = &[
-    "acidic",
-    "aged",
-    "agile",
-    "agreeable",
-    "airy",
-    "amber",
-    "ancient",
-    "angry",
-    "animated",
-    "anxious",
-    "aqua",
-    "aquamarine",
-    "arctic",
-    "aromatic",
-    "atomic",
-    "autumn",
-    "azure",
-    "balanced",
-    "balmy",
-    "bashful",
-    "beige",
-    "black",
-    "blazing",
-    "blissful",
-    "blue",
-    "bold",
-    "bouncy",
-    "breezy",
-    "bright",
-    "brilliant",
-    "brisk",
-    "brittle",
-    "bronze",
-    "brown",
-    "bubbling",
-    "bubbly",
-    "buoyant",
-    "buttery",
-    "buzzy",
-    "calm",
-    "candid",
-    "caramel",
-    "celestial",
-    "cheerful",
-    "cheery",
-    "chewy",
-    "chilly",
+/// Lowercase alphanumerics only, so different renderings of one pair compare equal.
+fn comparison_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Most bits a [`NameGenerator::with_provenance`] tag can have; 8 bits already leave only one
+/// name in 256.
+pub const MAX_PROVENANCE_BITS: u32 = 8;
+
+/// Random number generator behind a [`NameGenerator`]. Versions are never changed in place, so
+/// a seed keeps producing the same names under the version it was used with.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum RngVersion {
+    /// xorshift64* started directly from the seed; what [`NameGenerator::new`],
+    /// [`NameGenerator::from_seed`] and [`NameGenerator::from_state`] use.
+    #[default]
+    V1,
+    /// PCG (RXS M XS 64) seeded through SplitMix64: better distributed output, and nearby seeds
+    /// start far apart. Seeded names differ from [`RngVersion::V1`]. The `random_*_name`
+    /// functions draw with it.
+    V2,
+}
+
+/// Draws between the starts of two consecutive [`NameGenerator::stream`]s (2^40, enough for
+/// hundreds of billions of names per worker).
+pub const STREAM_LENGTH: u64 = 1 << 40;
+
+/// Deterministic generator that can be seeded manually for reproducible output.
+#[derive(Clone)]
+pub struct NameGenerator {
+    rng: TinyRng,
+    excluded_allergens: u16,
+    cuisines: u16,
+    categories: u16,
+    adjective_categories: u16,
+    max_tech_level: Option<TechLevel>,
+    format: Format<'static>,
+    prefix: String,
+    suffix: String,
+    random_suffix_len: usize,
+    random_suffix_alphabet: SuffixAlphabet,
+    blocked: HashSet<NamePair>,
+    unique: bool,
+    three_words: bool,
+    exhaustion: Exhaustion,
+    escalation: Option<Escalation>,
+    overflow_count: u64,
+    escalated: HashSet<(&'static str, NamePair)>,
+    sequence: Option<Sequence>,
+    issued: u64,
+    custom: Option<Vocabulary>,
+    redraws: u64,
+    locale: Locale,
+    provenance: Option<(u16, u32)>,
+    pools: HashMap<PoolKey, Pool>,
+}
+
+impl NameGenerator {
+    /// Create a generator that is automatically seeded with best-effort entropy, using
+    /// [`RngVersion::V1`] so its [`state`](NameGenerator::state) resumes with
+    /// [`NameGenerator::from_state`].
+    pub fn new() -> Self {
+        Self::new_with(RngVersion::V1)
+    }
+
+    /// [`NameGenerator::new`] using the random number generator `version`; checkpoints resume
+    /// with [`NameGenerator::from_state_with`] and the same version.
+    pub fn new_with(version: RngVersion) -> Self {
+        Self::with_rng(TinyRng::seed_from_entropy(version))
+    }
+
+    /// Create a generator from a fixed 64-bit seed, using [`RngVersion::V1`] so seeded output
+    /// never changes between crate versions.
+    pub fn from_seed(seed: u64) -> Self {
+        Self::with_rng(TinyRng::from_seed(seed))
+    }
+
+    /// Create a generator from a fixed 64-bit seed using the random number generator `version`
+    /// (see [`RngVersion`]).
+    pub fn from_seed_with(seed: u64, version: RngVersion) -> Self {
+        Self::with_rng(TinyRng::seeded(seed, version))
+    }
+
+    /// Create a generator seeded from arbitrary text (e.g. `"my-project"`), so a seed can be
+    /// shared as a memorable string. The same text gives the same names on every platform.
+    pub fn from_seed_str(seed: &str) -> Self {
+        Self::from_seed(stable_hash(seed.as_bytes()))
+    }
+
+    /// Create a generator that continues from a [`state`](NameGenerator::state) checkpoint.
+    ///
+    /// Only the random stream is restored: re-apply the configuration (format, filters, ...) with
+    /// the usual builders and reload issued names with [`NameGenerator::import_existing`].
+    ///
+    /// States are specific to an [`RngVersion`]; this restores a [`RngVersion::V1`] state (see
+    /// [`NameGenerator::from_state_with`]).
+    pub fn from_state(state: u64) -> Self {
+        Self::from_state_with(state, RngVersion::V1)
+    }
+
+    /// [`NameGenerator::from_state`] for a state taken from a generator using `version`.
+    pub fn from_state_with(state: u64, version: RngVersion) -> Self {
+        Self::with_rng(TinyRng::restored(state, version))
+    }
+
+    /// Random number generator version this generator draws with.
+    pub fn rng_version(&self) -> RngVersion {
+        self.rng.version
+    }
+
+    /// Restart this generator's random stream from `seed` in place, keeping its configuration
+    /// and blocked names.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = TinyRng::seeded(seed, self.rng.version);
+    }
+
+    /// Like [`NameGenerator::reseed`], with best-effort entropy instead of a fixed seed.
+    pub fn reseed_from_entropy(&mut self) {
+        self.rng = TinyRng::seed_from_entropy(self.rng.version);
+    }
+
+    /// Split off a child generator with the same configuration and blocked names but its own
+    /// random stream, e.g. one per worker thread. The child's seed is drawn from this generator,
+    /// so a seeded root forks the same children in the same order every run.
+    pub fn fork(&mut self) -> NameGenerator {
+        let mut child = self.clone();
+        child.rng = TinyRng::seeded(mix64(self.rng.next_u64()), self.rng.version);
+        child
+    }
+
+    /// Advance the random stream as if `draws` random numbers had been drawn, without drawing
+    /// them. Each name takes at least two draws (adjective and noun), more with filters, a random
+    /// suffix or redraws.
+    pub fn jump(&mut self, draws: u64) {
+        self.rng.jump(draws);
+    }
+
+    /// Copy of this generator for parallel worker `worker_id`, whose stream starts
+    /// [`STREAM_LENGTH`] draws after the previous worker's, so workers sharing one seed produce
+    /// disjoint, reproducible sequences as long as each draws fewer than [`STREAM_LENGTH`]
+    /// numbers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `worker_id` is 2^24 or above, whose stream would wrap around onto worker 0's.
+    pub fn stream(&self, worker_id: u64) -> NameGenerator {
+        let draws = worker_id
+            .checked_mul(STREAM_LENGTH)
+            .expect("worker ids must be below 2^24");
+        let mut worker = self.clone();
+        worker.rng.jump(draws);
+        worker
+    }
+
+    /// Position of this generator's random stream, to checkpoint with and resume from via
+    /// [`NameGenerator::from_state`].
+    pub fn state(&self) -> u64 {
+        self.rng.state
+    }
+
+    fn with_rng(rng: TinyRng) -> Self {
+        Self {
+            rng,
+            excluded_allergens: 0,
+            cuisines: 0,
+            categories: 0,
+            adjective_categories: 0,
+            max_tech_level: None,
+            format: Format::new(Case::Title, " "),
+            prefix: String::new(),
+            suffix: String::new(),
+            random_suffix_len: 0,
+            random_suffix_alphabet: SuffixAlphabet::Digits,
+            blocked: HashSet::new(),
+            unique: false,
+            three_words: false,
+            exhaustion: Exhaustion::Panic,
+            escalation: None,
+            overflow_count: 0,
+            escalated: HashSet::new(),
+            sequence: None,
+            issued: 0,
+            custom: None,
+            redraws: 0,
+            locale: Locale::En,
+            provenance: None,
+            pools: HashMap::new(),
+        }
+    }
+
+    /// Render the `*_name` helpers with `format` instead of Title Case with a space.
+    pub fn with_format(mut self, format: Format<'static>) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Append `digits` random digits to every name returned by the `*_name` helpers, joined with
+    /// the format's separator (e.g. `shiny-mango-4821`). The digits are drawn from this
+    /// generator's RNG, so seeded output stays reproducible; `0` turns the number off.
+    pub fn with_number_suffix(self, digits: usize) -> Self {
+        self.with_random_suffix(digits, SuffixAlphabet::Digits)
+    }
+
+    /// Like [`NameGenerator::with_number_suffix`], with `len` characters drawn from `alphabet`
+    /// (e.g. `shiny-mango-7f3a` with [`SuffixAlphabet::Hex`]) for systems that restrict the
+    /// characters a name may use.
+    pub fn with_random_suffix(mut self, len: usize, alphabet: SuffixAlphabet) -> Self {
+        self.random_suffix_len = len;
+        self.random_suffix_alphabet = alphabet;
+        self
+    }
+
+    /// Prepend `prefix` to every name returned by the `*_name` helpers (e.g. `stage-`).
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Append `suffix` to every name returned by the `*_name` helpers (e.g. `-eu1`).
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Use your own `adjectives` and `nouns` for [`NameGenerator::custom_words`] and
+    /// [`NameGenerator::custom_name`], validated like [`Vocabulary::new`]. Seeded generators stay
+    /// deterministic for a given pair of lists.
+    pub fn with_words<A, N>(
+        mut self,
+        adjectives: &[A],
+        nouns: &[N],
+    ) -> Result<Self, VocabularyError>
+    where
+        A: AsRef<str>,
+        N: AsRef<str>,
+    {
+        self.custom = Some(Vocabulary::new("custom", adjectives, nouns)?);
+        Ok(self)
+    }
+
+    /// Render the `*_name` helpers in `locale`.
+    ///
+    /// Pairs returned by the `*_words` helpers stay English, so tags, filters and
+    /// [`block`](NameGenerator::block) work the same in every locale; only the rendered names are
+    /// translated.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Start every name returned by the `*_name` helpers with `sequence`, so listings of generated
+    /// artifacts sort in the order the names were issued (e.g. `0042-zesty-quasar`).
+    pub fn with_sequence(mut self, sequence: Sequence) -> Self {
+        self.sequence = Some(sequence);
+        self
+    }
+
+    /// Only issue pairs whose [`provenance`](NamePair::provenance) over `bits` bits is `tag`
+    /// (e.g. a service or stream id), so the issuer of a name can be recovered with
+    /// [`NameGenerator::provenance_of`]. Each bit halves the names available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is above [`MAX_PROVENANCE_BITS`] or `tag` does not fit in `bits` bits.
+    pub fn with_provenance(mut self, tag: u16, bits: u32) -> Self {
+        assert!(
+            bits <= MAX_PROVENANCE_BITS,
+            "provenance tags have at most {MAX_PROVENANCE_BITS} bits"
+        );
+        assert!(
+            u32::from(tag) < 1 << bits,
+            "provenance tag {tag} does not fit in {bits} bits"
+        );
+        self.provenance = Some((tag, bits));
+        self.pools.clear();
+        self
+    }
+
+    /// Provenance tag of a name issued by a generator configured like this one (same
+    /// [`with_provenance`](NameGenerator::with_provenance) bits, prefix, suffix and so on);
+    /// `None` if the name cannot be parsed or no provenance is configured.
+    pub fn provenance_of(&self, name: &str) -> Option<u16> {
+        let (_, bits) = self.provenance?;
+        Some(self.parse_issued(name)?.provenance(bits))
+    }
+
+    /// [`block`](NameGenerator::block) every pair this generator returns, so no name repeats.
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Start every name returned by the `*_name` helpers with a second random adjective
+    /// (`Brisk Shiny Mango`), multiplying the names available by the number of adjectives.
+    /// Uniqueness and blocking still apply to the adjective + noun pair.
+    pub fn three_words(mut self) -> Self {
+        self.three_words = true;
+        self
+    }
+
+    /// Choose what happens once blocked names cover a theme's whole combination space.
+    pub fn on_exhaustion(mut self, policy: Exhaustion) -> Self {
+        self.exhaustion = policy;
+        self
+    }
+
+    /// Put `joiner` between the adjective and the noun of every name (see [`Format::with_joiner`]).
+    pub fn with_joiner(mut self, joiner: &'static str) -> Self {
+        self.format.joiner = Some(joiner);
+        self
+    }
+
+    /// Skip food nouns tagged with any of `allergens` in [`NameGenerator::food_words`] and
+    /// [`NameGenerator::food_name`] (see [`food_allergens`]).
+    pub fn exclude_allergens(mut self, allergens: &[Allergen]) -> Self {
+        for allergen in allergens {
+            self.excluded_allergens |= allergen.bit();
+        }
+        self
+    }
+
+    /// Only use food nouns tagged with at least one of `cuisines` in
+    /// [`NameGenerator::food_words`] and [`NameGenerator::food_name`] (see [`food_cuisines`]).
+    pub fn only_cuisines(mut self, cuisines: &[Cuisine]) -> Self {
+        for cuisine in cuisines {
+            self.cuisines |= cuisine.bit();
+        }
+        self
+    }
+
+    /// Only use nouns in one of `categories` in the food, sci-fi and hybrid helpers (see
+    /// [`noun_category`]), e.g. only seafood names or only spacecraft names.
+    ///
+    /// Drawing from a theme with no noun in any of `categories` panics.
+    pub fn only_categories(mut self, categories: &[Category]) -> Self {
+        for category in categories {
+            self.categories |= category.bit();
+        }
+        self
+    }
+
+    /// Only use adjectives in one of `categories` in the food and sci-fi helpers (see
+    /// [`adjective_category`]), e.g. only color adjectives for a palette.
+    ///
+    /// Drawing from a theme or locale with no adjective in any of `categories` panics.
+    pub fn only_adjective_categories(mut self, categories: &[AdjectiveCategory]) -> Self {
+        for category in categories {
+            self.adjective_categories |= category.bit();
+        }
+        self
+    }
+
+    /// Only use sci-fi nouns at or below `level` in [`NameGenerator::scifi_words`] and
+    /// [`NameGenerator::scifi_name`] (see [`scifi_tech_level`]).
+    pub fn max_tech_level(mut self, level: TechLevel) -> Self {
+        self.max_tech_level = Some(level);
+        self
+    }
+
+    /// Never return `pair` again from this generator.
+    ///
+    /// Blocked pairs are skipped by redrawing; once (nearly) every combination of a theme is
+    /// blocked, the [`Exhaustion`] policy decides what generation does instead of looping forever.
+    pub fn block(&mut self, pair: NamePair) {
+        if self.blocked.insert(pair) && is_tagged(self.provenance, &pair) {
+            for pool in self.pools.values_mut() {
+                if pool.contains(&pair)
+                    && let Some(free) = &mut pool.free
+                {
+                    *free -= 1;
+                }
+            }
+        }
+    }
+
+    /// Parse an inventory of already-issued names and [`block`](NameGenerator::block) them.
+    ///
+    /// Names may use any rendering (`Shiny Mango`, `shiny-mango`, `ShinyMango`, ...) and may carry
+    /// this generator's prefix, suffix and number suffix. Names that cannot be parsed back into a
+    /// built-in pair are returned so they can be reviewed.
+    pub fn import_existing<I, S>(&mut self, names: I) -> Vec<String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut unparsed = Vec::new();
+        for name in names {
+            let name = name.as_ref();
+            match self.parse_issued(name) {
+                Some(pair) => self.block(pair),
+                None => unparsed.push(name.to_string()),
+            }
+        }
+        unparsed
+    }
+
+    /// Summarize how much of each theme's combination space the blocked names use up.
+    ///
+    /// A pair whose noun appears in several themes counts towards each of them.
+    pub fn usage_report(&self) -> Vec<ThemeUsage> {
+        THEMES
+            .iter()
+            .map(|(theme, list)| ThemeUsage {
+                theme,
+                used: self
+                    .blocked
+                    .iter()
+                    .filter(|pair| {
+                        list.adjectives.contains(&pair.adjective) && list.nouns.contains(&pair.noun)
+                    })
+                    .count(),
+                total: list.adjectives.len() * list.nouns.len(),
+            })
+            .collect()
+    }
+
+    fn parse_issued(&self, name: &str) -> Option<NamePair> {
+        let name = name.strip_prefix(self.prefix.as_str()).unwrap_or(name);
+        let name = name.strip_suffix(self.suffix.as_str()).unwrap_or(name);
+        let name = match self.sequence {
+            Some(sequence) => sequence
+                .strip(name)?
+                .strip_prefix(&*self.format.written_separator())?,
+            None => name,
+        };
+        NamePair::parse(name).or_else(|| {
+            let len = self.random_suffix_len.checked_sub(1)?;
+            let (end, _) = name.char_indices().nth_back(len)?;
+            NamePair::parse(&name[..end])
+        })
+    }
+
+    /// Draw a free pair of `theme` from `words` as narrowed by `filters`, applying the exhaustion
+    /// policy once none is left.
+    fn pick(&mut self, theme: Theme, words: &WordLists, filters: Filters) -> NamePair {
+        if filters == Filters::default() {
+            return self.pick_unfiltered(theme, words);
+        }
+        self.pick_pooled(theme, words, filters)
+    }
+
+    /// [`NameGenerator::pick`] through the cached [`Pool`] for `theme` and `filters`.
+    ///
+    /// Pairs are drawn at random while free ones are plentiful; once fewer than one in
+    /// [`WALK_RATIO`] is free, one of the free pairs is chosen directly in list order, so the last
+    /// few names are always found in a single pass.
+    fn pick_pooled(&mut self, theme: Theme, words: &WordLists, filters: Filters) -> NamePair {
+        self.escalation = None;
+        let key = PoolKey {
+            theme,
+            locale: self.locale,
+            filters,
+        };
+        let Self {
+            pools,
+            blocked,
+            provenance,
+            rng,
+            redraws,
+            ..
+        } = self;
+        let pool = pools
+            .entry(key)
+            .or_insert_with(|| Pool::new(theme, words, filters));
+        let is_free = |pair: &NamePair| is_tagged(*provenance, pair) && !blocked.contains(pair);
+        let free = if blocked.is_empty() && provenance.is_none() {
+            pool.len()
+        } else if let Some(free) = pool.free {
+            free
+        } else {
+            let free = match provenance {
+                None => pool.len() - blocked.iter().filter(|pair| pool.contains(pair)).count(),
+                Some(_) => (0..pool.len())
+                    .filter(|&index| is_free(&pool.pair(index)))
+                    .count(),
+            };
+            pool.free = Some(free);
+            free
+        };
+        if free == 0 {
+            let pair = pool.draw(rng);
+            self.escalation = Some(self.escalate(pair));
+            return pair;
+        }
+        let pair = if free < pool.len() / WALK_RATIO {
+            let nth = rng.index(free);
+            (0..pool.len())
+                .map(|index| pool.pair(index))
+                .filter(|pair| is_free(pair))
+                .nth(nth)
+                .expect("free pair counts follow the blocklist")
+        } else {
+            loop {
+                let pair = pool.draw(rng);
+                if is_free(&pair) {
+                    break pair;
+                }
+                *redraws += 1;
+            }
+        };
+        if self.unique {
+            self.block(pair);
+        }
+        pair
+    }
+
+    fn escalate(&mut self, pair: NamePair) -> Escalation {
+        match self.exhaustion {
+            Exhaustion::Panic => panic!("blocked names cover the whole combination space"),
+            Exhaustion::NumberSuffix => {
+                self.overflow_count += 1;
+                Escalation::Number(self.overflow_count + 1)
+            }
+            Exhaustion::ThreeWords => {
+                let adjectives = self.locale.adjectives();
+                for _ in 0..MAX_DRAW_ATTEMPTS {
+                    let extra = adjectives[self.rng.index(adjectives.len())];
+                    if extra != pair.adjective && self.escalated.insert((extra, pair)) {
+                        return Escalation::Adjective(extra);
+                    }
+                }
+                panic!("three-word names cover the whole combination space");
+            }
+        }
+    }
+
+    /// [`NameGenerator::pick`] without filters: while nothing is blocked or tagged, the pair is
+    /// drawn straight from `words` without building a [`Pool`].
+    #[inline]
+    fn pick_unfiltered(&mut self, theme: Theme, words: &WordLists) -> NamePair {
+        if !self.blocked.is_empty() || self.provenance.is_some() {
+            return self.pick_pooled(theme, words, Filters::default());
+        }
+        self.escalation = None;
+        let pair = select_pair(words, &mut self.rng);
+        if self.unique {
+            self.block(pair);
+        }
+        pair
+    }
+
+    fn pick_from(&mut self, theme: Theme) -> NamePair {
+        self.pick_unfiltered(theme, &theme.list())
+    }
+
+    /// Filters that apply to food nouns, with adjectives narrowed by `adjective_categories`.
+    fn food_filters(&self, adjective_categories: u16) -> Filters {
+        Filters {
+            excluded_allergens: self.excluded_allergens,
+            cuisines: self.cuisines,
+            categories: self.categories,
+            adjective_categories,
+            max_tech_level: None,
+        }
+    }
+
+    /// Get an adjective + noun pair from `theme`, honouring the filters that apply to it.
+    pub fn words(&mut self, theme: Theme) -> NamePair {
+        match theme {
+            Theme::Food => self.food_words(),
+            Theme::SciFi => self.scifi_words(),
+            Theme::Hybrid => self.hybrid_words(),
+            _ => self.pick_from(theme),
+        }
+    }
+
+    /// Estimate what issuing `names` names from `theme` with this generator's configuration
+    /// (number suffix, blocklist, [`unique`](NameGenerator::unique) mode, ...) would look like, by
+    /// running `trials` independent copies of the generator. `self` is left untouched.
+    ///
+    /// Trials are seeded from this generator, so a seeded generator gives reproducible results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `trials` is zero, or if a unique generator with [`Exhaustion::Panic`] runs out
+    /// of names.
+    pub fn simulate(&self, theme: Theme, names: usize, trials: usize) -> Simulation {
+        assert!(trials > 0, "simulate needs at least one trial");
+        let mut seeds = self.rng;
+        let (mut collisions, mut redraws, mut colliding_trials) = (0, 0, 0);
+        for _ in 0..trials {
+            let mut trial = self.clone();
+            trial.rng = TinyRng::seeded(seeds.next_u64(), self.rng.version);
+            trial.redraws = 0;
+            let mut seen = HashSet::with_capacity(names);
+            let before = collisions;
+            for _ in 0..names {
+                if !seen.insert(trial.name(theme)) {
+                    collisions += 1;
+                }
+            }
+            colliding_trials += usize::from(collisions > before);
+            redraws += trial.redraws;
+        }
+        Simulation {
+            names,
+            trials,
+            mean_collisions: collisions as f64 / trials as f64,
+            collision_probability: colliding_trials as f64 / trials as f64,
+            mean_redraws: redraws as f64 / trials as f64,
+        }
+    }
+
+    /// Get an adjective + noun pair from the lists given to [`NameGenerator::with_words`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if no custom lists were configured.
+    pub fn custom_words(&mut self) -> NamePair {
+        let vocabulary = self
+            .custom
+            .expect("custom_words needs lists from NameGenerator::with_words");
+        self.words(Theme::Custom(vocabulary))
+    }
+
+    /// Convenience helper that returns a formatted name from the lists given to
+    /// [`NameGenerator::with_words`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if no custom lists were configured.
+    pub fn custom_name(&mut self) -> String {
+        let pair = self.custom_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted name from `theme`.
+    pub fn name(&mut self, theme: Theme) -> String {
+        let pair = self.words(theme);
+        self.decorate(pair)
+    }
+
+    /// Like [`NameGenerator::name`], also appending a `theme<TAB>adjective<TAB>noun` line for
+    /// the issued pair to `log`, so the sequence can be audited later with
+    /// [`NameGenerator::verify_log`].
+    pub fn name_logged(&mut self, theme: Theme, log: &mut impl io::Write) -> io::Result<String> {
+        let pair = self.words(theme);
+        writeln!(log, "{}\t{}\t{}", theme.name(), pair.adjective, pair.noun)?;
+        Ok(self.decorate(pair))
+    }
+
+    /// Replay a log written by [`NameGenerator::name_logged`], checking that this generator
+    /// issues exactly the logged pairs in order. Start from a generator configured and seeded
+    /// like the one that wrote the log (see [`verify_log`] for plain seeded generators).
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] on a malformed line or a theme that is not
+    /// built in.
+    pub fn verify_log(&mut self, log: impl BufRead) -> io::Result<LogVerdict> {
+        let mut entries = 0;
+        for (index, line) in log.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            let mut fields = line.split('\t');
+            let (Some(theme), Some(adjective), Some(noun), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "line {}: expected theme, adjective and noun separated by tabs",
+                        index + 1
+                    ),
+                ));
+            };
+            let theme = Theme::from_name(theme).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: unknown theme {theme:?}", index + 1),
+                )
+            })?;
+            let pair = self.words(theme);
+            self.decorate(pair);
+            if pair.adjective != adjective || pair.noun != noun {
+                return Ok(LogVerdict::Mismatch {
+                    line: index + 1,
+                    expected: pair,
+                });
+            }
+            entries += 1;
+        }
+        Ok(LogVerdict::Valid { entries })
+    }
+
+    /// [`NameGenerator::words`] for a theme fixed at compile time (see [`theme_functions!`]):
+    /// the theme's lists are constants, so nothing is dispatched at runtime.
+    #[inline]
+    pub fn words_of<T: StaticTheme>(&mut self) -> NamePair {
+        match T::THEME {
+            Theme::Food => self.food_words(),
+            Theme::SciFi => self.scifi_words(),
+            Theme::Hybrid => self.hybrid_words(),
+            _ => self.pick_unfiltered(T::THEME, &const { T::THEME.list() }),
+        }
+    }
+
+    /// [`NameGenerator::name`] for a theme fixed at compile time (see [`theme_functions!`]).
+    #[inline]
+    pub fn name_of<T: StaticTheme>(&mut self) -> String {
+        let pair = self.words_of::<T>();
+        self.decorate(pair)
+    }
+
+    /// Get a food-themed adjective + noun pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allergen and cuisine filters together exclude every food noun.
+    pub fn food_words(&mut self) -> NamePair {
+        let filters = self.food_filters(self.adjective_categories);
+        self.pick(Theme::Food, self.locale.food(), filters)
+    }
+
+    /// Get a sci-fi-themed adjective + noun pair.
+    pub fn scifi_words(&mut self) -> NamePair {
+        let filters = Filters {
+            categories: self.categories,
+            adjective_categories: self.adjective_categories,
+            max_tech_level: self.max_tech_level,
+            ..Filters::default()
+        };
+        self.pick(Theme::SciFi, self.locale.scifi(), filters)
+    }
+
+    /// Get a mineral-themed adjective + noun pair.
+    pub fn mineral_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Mineral)
+    }
+
+    /// Get a mythology-themed adjective + noun pair.
+    pub fn mythology_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Mythology)
+    }
+
+    /// Get an atompunk-themed adjective + noun pair.
+    pub fn atompunk_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Atompunk)
+    }
+
+    /// Get a weather-themed adjective + noun pair.
+    pub fn weather_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Weather)
+    }
+
+    /// Get a music-themed adjective + noun pair.
+    pub fn music_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Music)
+    }
+
+    /// Get an occupation-themed adjective + noun pair.
+    pub fn occupation_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Occupation)
+    }
+
+    /// Get a fantasy-themed adjective + noun pair.
+    pub fn fantasy_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Fantasy)
+    }
+
+    /// Get an animal-themed adjective + noun pair.
+    pub fn animal_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Animal)
+    }
+
+    /// Get a cyberpunk-themed adjective + noun pair.
+    pub fn cyberpunk_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Cyberpunk)
+    }
+
+    /// Get a celestial-themed adjective + noun pair.
+    pub fn celestial_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Celestial)
+    }
+
+    /// Get an ocean-themed adjective + noun pair.
+    pub fn ocean_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Ocean)
+    }
+
+    /// Get an adjective + chemical element pair such as `Silent Iridium`, handy for version
+    /// codenames.
+    pub fn element_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Element)
+    }
+
+    /// Get an arcade-themed adjective + noun pair.
+    pub fn arcade_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Arcade)
+    }
+
+    /// Get a herb or spice-themed adjective + noun pair.
+    pub fn herb_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Herb)
+    }
+
+    /// Get a dessert-themed adjective + noun pair.
+    pub fn dessert_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Dessert)
+    }
+
+    /// Get a drink-themed adjective + noun pair.
+    pub fn drink_words(&mut self) -> NamePair {
+        self.pick_from(Theme::Drink)
+    }
+
+    /// Get a sci-fi modifier + food noun pair such as `Plasma Pancake`. Food nouns follow the
+    /// allergen and cuisine filters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allergen and cuisine filters together exclude every food noun.
+    pub fn hybrid_words(&mut self) -> NamePair {
+        let filters = self.food_filters(0);
+        self.pick(Theme::Hybrid, &HYBRID_WORDS, filters)
+    }
+
+    /// Convenience helper that returns a formatted food name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn food_name(&mut self) -> String {
+        let pair = self.food_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted sci-fi name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn scifi_name(&mut self) -> String {
+        let pair = self.scifi_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted mineral name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn mineral_name(&mut self) -> String {
+        let pair = self.mineral_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted mythology name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn mythology_name(&mut self) -> String {
+        let pair = self.mythology_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted atompunk name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn atompunk_name(&mut self) -> String {
+        let pair = self.atompunk_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted weather name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn weather_name(&mut self) -> String {
+        let pair = self.weather_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted music name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn music_name(&mut self) -> String {
+        let pair = self.music_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted occupation name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn occupation_name(&mut self) -> String {
+        let pair = self.occupation_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted fantasy name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn fantasy_name(&mut self) -> String {
+        let pair = self.fantasy_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted animal name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn animal_name(&mut self) -> String {
+        let pair = self.animal_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted cyberpunk name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn cyberpunk_name(&mut self) -> String {
+        let pair = self.cyberpunk_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted celestial name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn celestial_name(&mut self) -> String {
+        let pair = self.celestial_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted ocean name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn ocean_name(&mut self) -> String {
+        let pair = self.ocean_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted element name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn element_name(&mut self) -> String {
+        let pair = self.element_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted arcade name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn arcade_name(&mut self) -> String {
+        let pair = self.arcade_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted herb or spice name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn herb_name(&mut self) -> String {
+        let pair = self.herb_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted dessert name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn dessert_name(&mut self) -> String {
+        let pair = self.dessert_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted drink name (Title Case with a space unless
+    /// configured otherwise).
+    pub fn drink_name(&mut self) -> String {
+        let pair = self.drink_words();
+        self.decorate(pair)
+    }
+
+    /// Convenience helper that returns a formatted hybrid sci-fi food name (Title Case with a
+    /// space unless configured otherwise).
+    pub fn hybrid_name(&mut self) -> String {
+        let pair = self.hybrid_words();
+        self.decorate(pair)
+    }
+
+    /// Get a robot/mecha designation: an alphanumeric code plus a sci-fi nickname.
+    pub fn robot_designation(&mut self) -> Designation {
+        select_designation(&mut self.rng)
+    }
+
+    /// Get a formatted name from `theme` plus a mascot noun from `mascot_theme`, e.g. a food team
+    /// name with a sci-fi mascot.
+    ///
+    /// The mascot depends only on the drawn pair, so a name always comes with the same mascot,
+    /// whichever generator drew it.
+    pub fn named_entity(&mut self, theme: Theme, mascot_theme: Theme) -> NamedEntity {
+        let pair = self.words(theme);
+        let mascots = mascot_theme.list().nouns;
+        let mascot = mascots[(pair.stable_hash() % mascots.len() as u64) as usize];
+        NamedEntity {
+            name: self.decorate(pair),
+            mascot,
+        }
+    }
+
+    /// A random adjective other than the one in `pair`, for [`NameGenerator::three_words`].
+    fn extra_adjective(&mut self, pair: NamePair) -> &'static str {
+        let adjectives = self.locale.adjectives();
+        loop {
+            let extra = adjectives[self.rng.index(adjectives.len())];
+            if extra != pair.adjective {
+                return extra;
+            }
+        }
+    }
+
+    /// Apply the configured format, number, prefix and suffix to `pair`.
+    fn decorate(&mut self, pair: NamePair) -> String {
+        let english = pair;
+        let pair = self.locale.translate_pair(english);
+        let format = self.locale.format(self.format);
+        let escalation = self.escalation.take().or_else(|| {
+            self.three_words
+                .then(|| Escalation::Adjective(self.extra_adjective(english)))
+        });
+        let name = match escalation {
+            None => pair.render(format),
+            Some(Escalation::Number(count)) => {
+                format!(
+                    "{}{}{count}",
+                    pair.render(format),
+                    format.written_separator()
+                )
+            }
+            Some(Escalation::Adjective(extra)) => {
+                let extra = self
+                    .locale
+                    .translate_pair(NamePair {
+                        adjective: extra,
+                        ..english
+                    })
+                    .adjective;
+                match format.order {
+                    WordOrder::AdjectiveFirst => {
+                        render_words(&[extra, pair.adjective, pair.noun], format)
+                    }
+                    WordOrder::NounFirst | WordOrder::Inverted => {
+                        render_words(&[pair.noun, pair.adjective, extra], format)
+                    }
+                }
+            }
+        };
+        let mut text = String::with_capacity(
+            self.prefix.len()
+                + name.len()
+                + self.format.separator.len()
+                + self.random_suffix_len
+                + self.suffix.len(),
+        );
+        let separator = self.format.written_separator();
+        text.push_str(&self.prefix);
+        if let Some(sequence) = self.sequence {
+            sequence.write(self.issued, &mut text);
+            text.push_str(&separator);
+        }
+        self.issued += 1;
+        text.push_str(&name);
+        if self.random_suffix_len > 0 {
+            let alphabet = self.random_suffix_alphabet.chars();
+            text.push_str(&separator);
+            for _ in 0..self.random_suffix_len {
+                text.push(char::from(alphabet[self.rng.index(alphabet.len())]));
+            }
+        }
+        text.push_str(&self.suffix);
+        text
+    }
+}
+
+/// Characters of the random suffix added by [`NameGenerator::with_random_suffix`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SuffixAlphabet {
+    /// `0-9`
+    #[default]
+    Digits,
+    /// Lowercase hexadecimal, `0-9a-f`.
+    Hex,
+    /// Lowercase Crockford base32: `0-9` and `a-z` without `i`, `l`, `o` and `u`.
+    Crockford,
+    /// `a-z`
+    Lowercase,
+}
+
+impl SuffixAlphabet {
+    /// The characters, in ascending order.
+    pub const fn chars(self) -> &'static [u8] {
+        match self {
+            Self::Digits => b"0123456789",
+            Self::Hex => b"0123456789abcdef",
+            Self::Crockford => b"0123456789abcdefghjkmnpqrstvwxyz",
+            Self::Lowercase => b"abcdefghijklmnopqrstuvwxyz",
+        }
+    }
+}
+
+/// Sort-friendly marker put before each name by [`NameGenerator::with_sequence`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Sequence {
+    /// Counter starting at `start`, zero-padded to `width` digits: `0042`. Counters that outgrow
+    /// `width` get longer and no longer sort as text.
+    Counter { start: u64, width: usize },
+    /// Current UTC time as `YYYYMMDDTHHMMSS`. Names issued within the same second share it.
+    Timestamp,
+}
+
+impl Sequence {
+    /// `name` without the marker [`Sequence::write`] put at its start.
+    fn strip(self, name: &str) -> Option<&str> {
+        let bytes = name.as_bytes();
+        let len = match self {
+            Sequence::Counter { width, .. } => {
+                let len = bytes
+                    .iter()
+                    .take_while(|byte| byte.is_ascii_digit())
+                    .count();
+                (len > 0 && len >= width).then_some(len)?
+            }
+            Sequence::Timestamp => {
+                let stamp = bytes.get(..15)?;
+                let is_stamp = stamp.iter().enumerate().all(|(index, byte)| match index {
+                    8 => *byte == b'T',
+                    _ => byte.is_ascii_digit(),
+                });
+                is_stamp.then_some(15)?
+            }
+        };
+        Some(&name[len..])
+    }
+
+    fn write(self, issued: u64, out: &mut String) {
+        use fmt::Write;
+
+        let _ = match self {
+            Sequence::Counter { start, width } => {
+                write!(out, "{:0width$}", start.saturating_add(issued))
+            }
+            Sequence::Timestamp => {
+                let secs = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |d| d.as_secs());
+                let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+                let time = secs % 86_400;
+                write!(
+                    out,
+                    "{year:04}{month:02}{day:02}T{:02}{:02}{:02}",
+                    time / 3_600,
+                    time / 60 % 60,
+                    time % 60
+                )
+            }
+        };
+    }
+}
+
+/// Language a [`NameGenerator`] renders names in (see [`NameGenerator::with_locale`]).
+///
+/// Each locale other than English sits behind a cargo feature of the same name (`es`, on by
+/// default, `de`, `fr` and `ja`). Only the food and sci-fi themes are translated, each from a
+/// smaller vocabulary than the English one; every other theme stays English.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Locale {
+    #[default]
+    En,
+    /// Spanish: noun first, with only the first word capitalized (`Mango brillante`).
+    #[cfg(feature = "es")]
+    Es,
+    /// German: adjectives agree with the gender of the noun (`Glänzendes Brot`).
+    #[cfg(feature = "de")]
+    De,
+    /// French: noun first, with only the first word capitalized (`Mangue calme`).
+    #[cfg(feature = "fr")]
+    Fr,
+    /// Japanese: words are written without a space between them (`輝くマンゴー`).
+    #[cfg(feature = "ja")]
+    Ja,
+}
+
+impl Locale {
+    /// Every locale enabled in this build, English first.
+    pub const ALL: &'static [Locale] = &[
+        Self::En,
+        #[cfg(feature = "es")]
+        Self::Es,
+        #[cfg(feature = "de")]
+        Self::De,
+        #[cfg(feature = "fr")]
+        Self::Fr,
+        #[cfg(feature = "ja")]
+        Self::Ja,
+    ];
+
+    /// ISO 639-1 code (e.g. `es`).
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            #[cfg(feature = "es")]
+            Self::Es => "es",
+            #[cfg(feature = "de")]
+            Self::De => "de",
+            #[cfg(feature = "fr")]
+            Self::Fr => "fr",
+            #[cfg(feature = "ja")]
+            Self::Ja => "ja",
+        }
+    }
+
+    /// Adjectives that have a translation in this locale.
+    const fn adjectives(self) -> &'static [&'static str] {
+        match self {
+            Self::En => ADJECTIVES,
+            #[cfg(feature = "es")]
+            Self::Es => es::ADJECTIVES,
+            #[cfg(feature = "de")]
+            Self::De => de::ADJECTIVES,
+            #[cfg(feature = "fr")]
+            Self::Fr => fr::ADJECTIVES,
+            #[cfg(feature = "ja")]
+            Self::Ja => ja::ADJECTIVES,
+        }
+    }
+
+    /// Food words that have a translation in this locale.
+    const fn food(self) -> &'static WordLists {
+        match self {
+            Self::En => &FOOD_WORDS,
+            #[cfg(feature = "es")]
+            Self::Es => &es::FOOD_WORDS,
+            #[cfg(feature = "de")]
+            Self::De => &de::FOOD_WORDS,
+            #[cfg(feature = "fr")]
+            Self::Fr => &fr::FOOD_WORDS,
+            #[cfg(feature = "ja")]
+            Self::Ja => &ja::FOOD_WORDS,
+        }
+    }
+
+    /// Sci-fi words that have a translation in this locale.
+    const fn scifi(self) -> &'static WordLists {
+        match self {
+            Self::En => &SCIFI_WORDS,
+            #[cfg(feature = "es")]
+            Self::Es => &es::SCIFI_WORDS,
+            #[cfg(feature = "de")]
+            Self::De => &de::SCIFI_WORDS,
+            #[cfg(feature = "fr")]
+            Self::Fr => &fr::SCIFI_WORDS,
+            #[cfg(feature = "ja")]
+            Self::Ja => &ja::SCIFI_WORDS,
+        }
+    }
+
+    fn translate_pair(self, pair: NamePair) -> NamePair {
+        match self {
+            Self::En => pair,
+            #[cfg(feature = "es")]
+            Self::Es => es::translate(pair),
+            #[cfg(feature = "de")]
+            Self::De => de::translate(pair),
+            #[cfg(feature = "fr")]
+            Self::Fr => fr::translate(pair),
+            #[cfg(feature = "ja")]
+            Self::Ja => ja::translate(pair),
+        }
+    }
+
+    /// `format` with this locale's default word order, title casing and word spacing.
+    fn format(self, format: Format<'static>) -> Format<'static> {
+        match self {
+            Self::En => format,
+            #[cfg(feature = "de")]
+            Self::De => format,
+            #[cfg(feature = "es")]
+            Self::Es => noun_first_sentence(format),
+            #[cfg(feature = "fr")]
+            Self::Fr => noun_first_sentence(format),
+            #[cfg(feature = "ja")]
+            Self::Ja => Format {
+                separator: if format.separator == " " {
+                    ""
+                } else {
+                    format.separator
+                },
+                ..format
+            },
+        }
+    }
+}
+
+/// Romance-language order: the noun first and, in human-readable title formats, only the first
+/// word capitalized.
+#[cfg(any(feature = "es", feature = "fr"))]
+fn noun_first_sentence(format: Format<'static>) -> Format<'static> {
+    Format {
+        order: match format.order {
+            WordOrder::AdjectiveFirst => WordOrder::NounFirst,
+            order => order,
+        },
+        case: match format.case {
+            Case::Title if !format.separator.is_empty() => Case::Sentence,
+            case => case,
+        },
+        ..format
+    }
+}
+
+/// Draws made before deciding every remaining candidate is taken, where free candidates are not
+/// counted (three-word escalation, rename tables, incident codenames).
+const MAX_DRAW_ATTEMPTS: usize = 100_000;
+
+/// Once fewer than one pair in this many is free, [`NameGenerator`] stops drawing at random and
+/// picks among the free pairs directly.
+const WALK_RATIO: usize = 64;
+
+/// What a generator does once blocked names (see [`NameGenerator::unique`]) cover a theme's whole
+/// combination space.
+///
+/// The policies shape the `*_name` helpers; the `*_words` helpers return a repeated pair.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Exhaustion {
+    /// Panic, so running out of names is an error rather than a silent repeat.
+    #[default]
+    Panic,
+    /// Reuse pairs with an increasing counter appended: `Shiny Mango 2`, `Brave Kiwi 3`, ...
+    NumberSuffix,
+    /// Escalate to three-word names with a second adjective: `Brave Shiny Mango`.
+    ThreeWords,
+}
+
+/// How an exhausted draw is made unique again when it is rendered.
+#[derive(Clone, Copy, Debug)]
+enum Escalation {
+    Number(u64),
+    Adjective(&'static str),
+}
+
+impl Default for NameGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How much of one theme's combination space is taken, from [`NameGenerator::usage_report`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ThemeUsage {
+    pub theme: &'static str,
+    pub used: usize,
+    pub total: usize,
+}
+
+impl ThemeUsage {
+    /// Share of the combination space already used, from 0.0 to 100.0.
+    pub fn percent_used(&self) -> f64 {
+        self.used as f64 * 100.0 / self.total as f64
+    }
+
+    /// Names still available in this theme.
+    pub fn remaining(&self) -> usize {
+        self.total.saturating_sub(self.used)
+    }
+
+    /// Periods until the theme runs out if `per_period` new names are issued each period
+    /// (e.g. names per day gives days). Returns `None` when nothing is being issued.
+    pub fn periods_until_exhausted(&self, per_period: f64) -> Option<f64> {
+        (per_period > 0.0).then(|| self.remaining() as f64 / per_period)
+    }
+}
+
+/// Outcome of [`NameGenerator::simulate`], averaged over its trials.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Simulation {
+    /// Names issued per trial.
+    pub names: usize,
+    pub trials: usize,
+    /// Average number of names per trial that repeated an earlier name of the same trial.
+    pub mean_collisions: f64,
+    /// Share of trials with at least one collision, from 0.0 to 1.0.
+    pub collision_probability: f64,
+    /// Average number of draws per trial thrown away because the pair was blocked.
+    pub mean_redraws: f64,
+}
+
+/// Size of one theme's combination space, from [`theme_stats`].
+#[derive(Clone, Copy, Debug)]
+pub struct ThemeStats {
+    pub theme: &'static str,
+    list: &'static WordLists,
+}
+
+impl ThemeStats {
+    /// Number of adjectives the theme draws from.
+    pub fn adjectives(&self) -> usize {
+        self.list.adjectives.len()
+    }
+
+    /// Number of nouns the theme draws from.
+    pub fn nouns(&self) -> usize {
+        self.list.nouns.len()
+    }
+
+    /// Number of distinct adjective + noun pairs.
+    pub fn combinations(&self) -> usize {
+        self.adjectives() * self.nouns()
+    }
+
+    /// Bits of entropy in one uniformly drawn pair.
+    pub fn entropy_bits(&self) -> f64 {
+        (self.combinations() as f64).log2()
+    }
+
+    /// Shortest and longest name in characters when rendered with `format` (its `max_len` and
+    /// joiner are ignored).
+    pub fn name_lengths(&self, format: Format<'_>) -> (usize, usize) {
+        name_lengths(self.list, format)
+    }
+
+    /// Metadata for every adjective, in list order.
+    pub fn adjective_info(&self) -> impl Iterator<Item = WordInfo> + use<> {
+        self.list.adjectives.iter().map(|word| WordInfo::of(word))
+    }
+
+    /// Metadata for every noun, in list order.
+    pub fn noun_info(&self) -> impl Iterator<Item = WordInfo> + use<> {
+        self.list.nouns.iter().map(|word| WordInfo::of(word))
+    }
+}
+
+/// Length and rhythm of one word, for length-budgeted or rhythm-aware names.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WordInfo {
+    pub word: &'static str,
+    /// Length in characters (`crème brûlée` is 12).
+    pub chars: usize,
+    /// Estimated syllable count; see [`count_syllables`].
+    pub syllables: usize,
+}
+
+impl WordInfo {
+    /// Describe `word`.
+    pub fn of(word: &'static str) -> WordInfo {
+        WordInfo {
+            word,
+            chars: word.chars().count(),
+            syllables: count_syllables(word),
+        }
+    }
+}
+
+/// Estimate the syllables in `text` by counting vowel groups in each word, discounting a silent
+/// final `e` (`cake` is 1, `apple` is 2).
+///
+/// English spelling defeats any short rule, so a few words are off by one (`quinoa` counts 2).
+pub fn count_syllables(text: &str) -> usize {
+    text.split(|ch: char| is_word_break(ch) || ch.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let letters: Vec<char> = word
+                .chars()
+                .filter(|ch| ch.is_alphabetic())
+                .flat_map(char::to_lowercase)
+                .map(fold_vowel)
+                .collect();
+            let is_vowel = |ch: char| "aeiouy".contains(ch);
+            let mut groups = 0;
+            for (index, &ch) in letters.iter().enumerate() {
+                if is_vowel(ch) && (index == 0 || !is_vowel(letters[index - 1])) {
+                    groups += 1;
+                }
+            }
+            let silent_e = match letters.as_slice() {
+                [.., before, 'l', 'e'] => is_vowel(*before),
+                [.., before, 'e'] => !is_vowel(*before),
+                _ => false,
+            };
+            if silent_e && groups > 1 {
+                groups -= 1;
+            }
+            groups.max(1)
+        })
+        .sum()
+}
+
+/// Map accented vowels to their base letter; `é` and `è` are only ever sounded in the word lists.
+fn fold_vowel(ch: char) -> char {
+    match ch {
+        'à' | 'á' | 'â' | 'ä' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'ö' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        ch => ch,
+    }
+}
+
+/// Number of shapes an [`Avatar::shape`] index chooses between.
+pub const AVATAR_SHAPES: u8 = 6;
+
+/// Placeholder avatar from [`NamePair::avatar`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Avatar {
+    /// Uppercase first letters of the adjective and noun (`SM` for `Shiny Mango`).
+    pub initials: String,
+    /// Background as RGB, dark enough for white initials.
+    pub background: [u8; 3],
+    /// Shape index below [`AVATAR_SHAPES`], for frontends to map to their own shapes.
+    pub shape: u8,
+}
+
+impl Avatar {
+    /// Background as a CSS hex color (`#3a7bb4`).
+    pub fn background_hex(&self) -> String {
+        let [r, g, b] = self.background;
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+/// RGB for `hue` degrees at a fixed saturation and lightness, so every avatar has similar contrast.
+fn hue_to_rgb(hue: f64) -> [u8; 3] {
+    const SATURATION: f64 = 0.55;
+    const LIGHTNESS: f64 = 0.42;
+    let chroma = (1.0 - (2.0 * LIGHTNESS - 1.0).abs()) * SATURATION;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (r, g, b) = match (hue / 60.0) as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let offset = LIGHTNESS - chroma / 2.0;
+    [r, g, b].map(|channel| ((channel + offset) * 255.0).round() as u8)
+}
+
+/// Whether `name` is a built-in pair followed by its check digit, as rendered by
+/// [`NamePair::with_check_digit`]. Case and separators are ignored (`zesty-quasar-7` passes).
+pub fn verify_check_digit(name: &str) -> bool {
+    let name = name.trim_end();
+    let Some(digit) = name.chars().last().and_then(|c| c.to_digit(10)) else {
+        return false;
+    };
+    NamePair::parse(&name[..name.len() - 1])
+        .is_some_and(|pair| u32::from(pair.check_digit()) == digit)
+}
+
+/// Raw adjective + noun pair.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NamePair {
+    pub adjective: &'static str,
+    pub noun: &'static str,
+}
+
+impl NamePair {
+    /// Parse a rendered name back into a built-in pair, whatever format it was rendered in
+    /// (`Shiny Mango`, `shiny-mango`, `SHINY_MANGO`, `shinyMango`, ...).
+    pub fn parse(name: &str) -> Option<NamePair> {
+        let key = comparison_key(name);
+        ADJECTIVES
+            .iter()
+            .chain(SCIFI_MODIFIERS)
+            .find_map(|adjective| {
+                let rest = key.strip_prefix(adjective)?;
+                let noun = noun_keys().get(rest)?;
+                Some(NamePair { adjective, noun })
+            })
+    }
+
+    /// Render the pair as `Titlecase Titlecase`.
+    pub fn title_case(&self) -> String {
+        self.render(Format::default())
+    }
+
+    /// Render the pair as `lowercase lowercase` (e.g. `shiny mango`).
+    pub fn lowercase(&self) -> String {
+        self.render(Format::new(Case::Lower, " "))
+    }
+
+    /// Render the pair as `UPPERCASE UPPERCASE` (e.g. `SHINY MANGO`).
+    pub fn uppercase(&self) -> String {
+        self.render(Format::new(Case::Upper, " "))
+    }
+
+    /// Render the pair as `lowercase_lowercase`, splitting multi-word nouns (e.g. `black_cod`).
+    pub fn snake_case(&self) -> String {
+        self.render(Format::new(Case::Lower, "_"))
+    }
+
+    /// Render the pair as `PascalCase`, joining multi-word nouns (e.g. `BriskSpaceTime`).
+    pub fn pascal_case(&self) -> String {
+        self.render(Format::new(Case::Title, ""))
+    }
+
+    /// Render the pair as `camelCase`, joining multi-word nouns (e.g. `briskSpaceTime`).
+    pub fn camel_case(&self) -> String {
+        self.render(Format::new(Case::Camel, ""))
+    }
+
+    /// Render the pair as an identifier matching `[a-z][a-z0-9_]*` (e.g. `brisk_space_time`).
+    ///
+    /// Unlike [`NamePair::snake_case`], any character outside that set is replaced, so the result
+    /// is safe to use as a Rust or Python identifier for every theme.
+    pub fn identifier(&self) -> String {
+        let mut text = String::with_capacity(self.adjective.len() + self.noun.len() + 1);
+        for ch in self
+            .render(Format::new(Case::Lower, "_").ascii_only())
+            .chars()
+        {
+            let ch = if ch.is_ascii_lowercase() || ch.is_ascii_digit() {
+                ch
+            } else {
+                '_'
+            };
+            if ch == '_' && (text.is_empty() || text.ends_with('_')) {
+                continue;
+            }
+            text.push(ch);
+        }
+        while text.ends_with('_') {
+            text.pop();
+        }
+        if !text.starts_with(|c: char| c.is_ascii_lowercase()) {
+            text.insert_str(0, "name_");
+        }
+        text
+    }
+
+    /// Render the pair as an RFC 1123 DNS label (e.g. `shiny-black-cod`).
+    ///
+    /// The result only contains `[a-z0-9-]`, starts and ends with an alphanumeric character and is
+    /// at most 63 characters long, so it can be used directly as a Kubernetes pod or service name.
+    pub fn dns_label(&self) -> String {
+        const MAX_LABEL_LEN: usize = 63;
+
+        let mut text = String::with_capacity(self.adjective.len() + self.noun.len() + 1);
+        for ch in self
+            .render(Format::new(Case::Lower, "-").ascii_only())
+            .chars()
+        {
+            let ch = if ch.is_ascii_lowercase() || ch.is_ascii_digit() {
+                ch
+            } else {
+                '-'
+            };
+            if ch == '-' && (text.is_empty() || text.ends_with('-')) {
+                continue;
+            }
+            text.push(ch);
+        }
+        text.truncate(MAX_LABEL_LEN);
+        while text.ends_with('-') {
+            text.pop();
+        }
+        if text.is_empty() {
+            text.push_str("name");
+        }
+        text
+    }
+
+    /// Whether both words are pure ASCII.
+    pub fn is_ascii(&self) -> bool {
+        self.adjective.is_ascii() && self.noun.is_ascii()
+    }
+
+    /// Canonical storage key `theme:noun:adjective` (e.g. `food:black-cod:sunny`), the same no
+    /// matter which format the name was displayed in.
+    ///
+    /// Words are lowercase ASCII with `-` between words. A noun listed in several themes uses the
+    /// first of them; pairs outside the built-in themes use `custom`.
+    pub fn key(&self) -> String {
+        let theme = THEMES
+            .iter()
+            .find(|(_, list)| {
+                list.adjectives.contains(&self.adjective) && list.nouns.contains(&self.noun)
+            })
+            .map_or("custom", |(theme, _)| theme);
+        let format = Format::new(Case::Lower, "-").ascii_only();
+        format!(
+            "{theme}:{}:{}",
+            render_words(&[self.noun], format),
+            render_words(&[self.adjective], format)
+        )
+    }
+
+    /// Every word of the pair in order, with multi-word nouns split apart
+    /// (`smoky mahi mahi` yields `smoky`, `mahi`, `mahi`).
+    pub fn tokens(&self) -> impl Iterator<Item = &'static str> {
+        [self.adjective, self.noun]
+            .into_iter()
+            .flat_map(|word| word.split(is_word_break))
+            .filter(|token| !token.is_empty())
+    }
+
+    /// Breed two pairs: the child takes its adjective from one parent and its noun from the other,
+    /// which parent gives which being drawn from `generator`'s RNG.
+    pub fn crossover(&self, other: &NamePair, generator: &mut NameGenerator) -> NamePair {
+        let (first, second) = if generator.rng.next_u64() & 1 == 0 {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        NamePair {
+            adjective: first.adjective,
+            noun: second.noun,
+        }
+    }
+
+    /// Replace one word with a different word from the built-in list it comes from, picking the
+    /// adjective or the noun in proportion to `adjective_weight` and `noun_weight`.
+    ///
+    /// Words outside the built-in lists are never replaced, so such a pair may come back as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if both weights are zero.
+    pub fn mutate_weighted(
+        &self,
+        adjective_weight: u32,
+        noun_weight: u32,
+        generator: &mut NameGenerator,
+    ) -> NamePair {
+        let total = u64::from(adjective_weight) + u64::from(noun_weight);
+        assert!(total > 0, "mutate_weighted needs a non-zero weight");
+        let rng = &mut generator.rng;
+        let mut pair = *self;
+        if rng.next_u64() % total < u64::from(adjective_weight) {
+            let lists = THEMES.iter().map(|(_, list)| list.adjectives);
+            pair.adjective = replace_word(pair.adjective, lists, rng);
+        } else {
+            let lists = THEMES.iter().map(|(_, list)| list.nouns);
+            pair.noun = replace_word(pair.noun, lists, rng);
+        }
+        pair
+    }
+
+    /// Placeholder avatar for the pair: initials, background color and shape, all derived from
+    /// the words so a name always gets the same avatar.
+    pub fn avatar(&self) -> Avatar {
+        let initials = [self.adjective, self.noun]
+            .iter()
+            .filter_map(|word| word.chars().next())
+            .flat_map(char::to_uppercase)
+            .collect();
+        let hash = self.stable_hash();
+        Avatar {
+            initials,
+            background: hue_to_rgb((hash % 360) as f64),
+            shape: ((hash >> 32) % u64::from(AVATAR_SHAPES)) as u8,
+        }
+    }
+
+    /// Provenance tag carried by the pair: the low `bits` bits of its stable hash (see
+    /// [`NameGenerator::with_provenance`]).
+    pub fn provenance(&self, bits: u32) -> u16 {
+        (self.stable_hash() & ((1 << bits) - 1)) as u16
+    }
+
+    /// Check digit (0-9) derived from both words, for [`NamePair::with_check_digit`].
+    pub fn check_digit(&self) -> u8 {
+        (self.stable_hash() % 10) as u8
+    }
+
+    /// [`stable_hash`] of both words, kept apart by a 0xff byte (never part of UTF-8).
+    fn stable_hash(&self) -> u64 {
+        let mut key = Vec::with_capacity(self.adjective.len() + self.noun.len() + 1);
+        key.extend_from_slice(self.adjective.as_bytes());
+        key.push(0xff);
+        key.extend_from_slice(self.noun.as_bytes());
+        stable_hash(&key)
+    }
+
+    /// Render the pair in Title Case followed by its [check digit](NamePair::check_digit)
+    /// (e.g. `Zesty Quasar 7`), so a name read back over voice can be checked with
+    /// [`verify_check_digit`].
+    pub fn with_check_digit(&self) -> String {
+        format!("{} {}", self.title_case(), self.check_digit())
+    }
+
+    /// Estimated syllables in the whole name (see [`count_syllables`]).
+    pub fn syllables(&self) -> usize {
+        count_syllables(self.adjective) + count_syllables(self.noun)
+    }
+
+    /// Render the pair in Title Case with a custom separator between every word (e.g. `Shiny.Mango`).
+    pub fn join(&self, separator: &str) -> String {
+        self.render(Format::new(Case::Title, separator))
+    }
+
+    /// Render the pair with the given casing and separator.
+    pub fn render(&self, format: Format<'_>) -> String {
+        let text = self.render_with(self.adjective, format);
+        match format.max_len {
+            Some(limit) if text.len() > limit => self.shorten(format, limit),
+            _ => text,
+        }
+    }
+
+    fn shorten(&self, format: Format<'_>, limit: usize) -> String {
+        let text = match format.overflow {
+            Overflow::TruncateNoun => self.render_with(self.adjective, format),
+            Overflow::DropAdjective => render_words(&[self.noun], format),
+            Overflow::Abbreviate => {
+                let initial = self.adjective.chars().next().map_or(0, char::len_utf8);
+                self.render_with(&self.adjective[..initial], format)
+            }
+        };
+        truncate_name(text, limit, format.separator)
+    }
+
+    fn render_with(&self, adjective: &str, format: Format<'_>) -> String {
+        let mut text = String::with_capacity(adjective.len() + self.noun.len() + 8);
+        self.write_with(adjective, format, &mut text)
+            .expect("writing to a String cannot fail");
+        text
+    }
+
+    /// Write `adjective` and the noun with `format`, in its word order and with its joiner word.
+    fn write_with(
+        &self,
+        adjective: &str,
+        format: Format<'_>,
+        out: &mut impl fmt::Write,
+    ) -> fmt::Result {
+        if format.ascii {
+            // Fold the separators and joiner along with the words.
+            let format = Format {
+                ascii: false,
+                ..format
+            };
+            return self.write_unfolded(adjective, format, &mut AsciiFold(out));
+        }
+        self.write_unfolded(adjective, format, out)
+    }
+
+    fn write_unfolded(
+        &self,
+        adjective: &str,
+        format: Format<'_>,
+        out: &mut impl fmt::Write,
+    ) -> fmt::Result {
+        let (first, second) = match format.order {
+            WordOrder::AdjectiveFirst => (adjective, self.noun),
+            WordOrder::NounFirst | WordOrder::Inverted => (self.noun, adjective),
+        };
+        if format.order == WordOrder::Inverted {
+            write_words(&[first], format, out)?;
+            out.write_char(',')?;
+            out.write_str(format.separator)?;
+            return write_words(&[second], format, out);
+        }
+        match format.joiner {
+            None => write_words(&[first, second], format, out),
+            Some(joiner) if format.case == Case::Title => {
+                write_words(&[first], format, out)?;
+                out.write_str(format.separator)?;
+                out.write_str(joiner)?;
+                out.write_str(format.separator)?;
+                write_words(&[second], format, out)
+            }
+            Some(joiner) => write_words(&[first, joiner, second], format, out),
+        }
+    }
+
+    /// Write the pair as `Titlecase Titlecase` into an existing buffer without allocating.
+    pub fn write_title_case(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        self.write_rendered(out, Format::default())
+    }
+
+    /// Write the pair as `lowercase lowercase` into an existing buffer without allocating.
+    pub fn write_lowercase(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        self.write_rendered(out, Format::new(Case::Lower, " "))
+    }
+
+    /// Write the pair as `UPPERCASE UPPERCASE` into an existing buffer without allocating.
+    pub fn write_uppercase(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        self.write_rendered(out, Format::new(Case::Upper, " "))
+    }
+
+    /// Write the pair as `lowercase_lowercase` into an existing buffer without allocating.
+    pub fn write_snake_case(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        self.write_rendered(out, Format::new(Case::Lower, "_"))
+    }
+
+    /// Write the pair as `PascalCase` into an existing buffer without allocating.
+    pub fn write_pascal_case(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        self.write_rendered(out, Format::new(Case::Title, ""))
+    }
+
+    /// Write the pair as `camelCase` into an existing buffer without allocating.
+    pub fn write_camel_case(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        self.write_rendered(out, Format::new(Case::Camel, ""))
+    }
+
+    /// Write the pair with the given casing and separator into an existing buffer.
+    ///
+    /// This only allocates when [`Format::max_len`] is set and the name has to be shortened.
+    pub fn write_rendered(&self, out: &mut impl fmt::Write, format: Format<'_>) -> fmt::Result {
+        match format.max_len {
+            Some(limit) if self.rendered_len(format) > limit => {
+                out.write_str(&self.shorten(format, limit))
+            }
+            _ => self.write_with(self.adjective, format, out),
+        }
+    }
+
+    fn rendered_len(&self, format: Format<'_>) -> usize {
+        let mut counter = LenCounter(0);
+        let _ = self.write_with(self.adjective, format, &mut counter);
+        counter.0
+    }
+
+    /// Render the pair through a template such as `{adjective}-{noun}-{rand4}`.
+    ///
+    /// Supported placeholders:
+    ///
+    /// * `{adjective}`, `{noun}` and `{name}` (the whole pair), optionally followed by a style:
+    ///   `:lower` (default), `:upper`, `:title`, `:snake`, `:kebab`, `:pascal` or `:camel`.
+    /// * `{randN}`: `N` random digits (1 to 32), e.g. `{rand4}` → `4821`.
+    /// * `{date}` (`YYYY-MM-DD`), `{year}`, `{month}` and `{day}`, using the current UTC date.
+    ///
+    /// Use `{{` and `}}` for literal braces. Random digits come from the thread-local generator;
+    /// use [`NamePair::format_with`] for reproducible output.
+    pub fn format(&self, template: &str) -> Result<String, TemplateError> {
+        GLOBAL_RNG.with(|rng| render_template(self, template, &mut rng.borrow_mut(), today_utc()))
+    }
+
+    /// Same as [`NamePair::format`], but random digits are drawn from `generator`.
+    pub fn format_with(
+        &self,
+        template: &str,
+        generator: &mut NameGenerator,
+    ) -> Result<String, TemplateError> {
+        render_template(self, template, &mut generator.rng, today_utc())
+    }
+}
+
+impl fmt::Display for NamePair {
+    /// Formats the pair in Title Case, the same as [`NamePair::title_case`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_title_case(f)
+    }
+}
+
+/// Built-in word themes, for choosing a theme at runtime (e.g. from configuration).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Theme {
+    Food,
+    SciFi,
+    Mineral,
+    Mythology,
+    Atompunk,
+    Weather,
+    Music,
+    Occupation,
+    Fantasy,
+    Animal,
+    Cyberpunk,
+    Celestial,
+    Ocean,
+    Element,
+    Arcade,
+    Herb,
+    Dessert,
+    Drink,
+    /// Sci-fi modifiers with food nouns (`Quantum Quinoa`).
+    Hybrid,
+    /// Words supplied at runtime.
+    Custom(Vocabulary),
+}
+
+impl Theme {
+    /// Every theme, in the order used by [`export_vocabulary`] and [`theme_stats`].
+    pub const ALL: [Theme; 19] = [
+        Self::Food,
+        Self::SciFi,
+        Self::Mineral,
+        Self::Mythology,
+        Self::Atompunk,
+        Self::Weather,
+        Self::Music,
+        Self::Occupation,
+        Self::Fantasy,
+        Self::Animal,
+        Self::Cyberpunk,
+        Self::Celestial,
+        Self::Ocean,
+        Self::Element,
+        Self::Arcade,
+        Self::Herb,
+        Self::Dessert,
+        Self::Drink,
+        Self::Hybrid,
+    ];
+
+    /// Lowercase name used in exports and storage keys (e.g. `scifi`); custom themes use the name
+    /// they were created with.
+    pub const fn name(self) -> &'static str {
+        self.entry().0
+    }
+
+    /// Look a built-in theme up by its [`name`](Theme::name); see [`ThemeRegistry`] for custom
+    /// themes.
+    pub fn from_name(name: &str) -> Option<Theme> {
+        Self::ALL.into_iter().find(|theme| theme.name() == name)
+    }
+
+    /// Load a custom theme from JSON: `{"adjectives": ["brisk", ...], "nouns": ["harbor", ...]}`.
+    ///
+    /// The words are leaked like those of [`Vocabulary::new`]: every successful call keeps its
+    /// copy until the program exits, so load each theme once (e.g. at startup), not per request.
+    pub fn from_json(name: &str, json: &str) -> Result<Theme, ThemeLoadError> {
+        let mut scanner = Scanner::new(json, false);
+        let (mut adjectives, mut nouns) = (Vec::new(), Vec::new());
+        scanner.expect('{', "expected `{`")?;
+        if !scanner.eat('}') {
+            loop {
+                let key = scanner.string()?;
+                scanner.expect(':', "expected `:`")?;
+                *scanner.list_for(&key, &mut adjectives, &mut nouns)? = scanner.string_array()?;
+                if !scanner.eat(',') {
+                    scanner.expect('}', "expected `,` or `}`")?;
+                    break;
+                }
+            }
+        }
+        scanner.finish()?;
+        Ok(Theme::Custom(Vocabulary::new(name, &adjectives, &nouns)?))
+    }
+
+    /// Load a custom theme from TOML: `adjectives = ["brisk", ...]` and `nouns = [...]`. Arrays may
+    /// span several lines and `#` starts a comment. Leaks its words like [`Theme::from_json`].
+    pub fn from_toml(name: &str, toml: &str) -> Result<Theme, ThemeLoadError> {
+        let mut scanner = Scanner::new(toml, true);
+        let (mut adjectives, mut nouns) = (Vec::new(), Vec::new());
+        while !scanner.at_end() {
+            let key = scanner.bare_key()?;
+            scanner.expect('=', "expected `=`")?;
+            *scanner.list_for(&key, &mut adjectives, &mut nouns)? = scanner.string_array()?;
+        }
+        Ok(Theme::Custom(Vocabulary::new(name, &adjectives, &nouns)?))
+    }
+
+    /// Load a custom theme from plain text with one word per line under `[adjectives]` and
+    /// `[nouns]` headers. Blank lines and lines starting with `#` are skipped. Leaks its words like
+    /// [`Theme::from_json`].
+    pub fn from_lines(name: &str, reader: impl BufRead) -> Result<Theme, ThemeLoadError> {
+        let (mut adjectives, mut nouns) = (Vec::new(), Vec::new());
+        let mut section = None;
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let word = line.trim();
+            match word {
+                "" => {}
+                _ if word.starts_with('#') => {}
+                "[adjectives]" => section = Some(&mut adjectives),
+                "[nouns]" => section = Some(&mut nouns),
+                _ => match section.as_deref_mut() {
+                    Some(list) => list.push(word.to_string()),
+                    None => {
+                        return Err(ThemeLoadError::Syntax {
+                            line: index + 1,
+                            message: "word before an `[adjectives]` or `[nouns]` header",
+                        });
+                    }
+                },
+            }
+        }
+        Ok(Theme::Custom(Vocabulary::new(name, &adjectives, &nouns)?))
+    }
+
+    const fn list(self) -> WordLists {
+        self.entry().1
+    }
+
+    const fn entry(self) -> (&'static str, WordLists) {
+        let index = match self {
+            Self::Food => 0,
+            Self::SciFi => 1,
+            Self::Mineral => 2,
+            Self::Mythology => 3,
+            Self::Atompunk => 4,
+            Self::Weather => 5,
+            Self::Music => 6,
+            Self::Occupation => 7,
+            Self::Fantasy => 8,
+            Self::Animal => 9,
+            Self::Cyberpunk => 10,
+            Self::Celestial => 11,
+            Self::Ocean => 12,
+            Self::Element => 13,
+            Self::Arcade => 14,
+            Self::Herb => 15,
+            Self::Dessert => 16,
+            Self::Drink => 17,
+            Self::Hybrid => 18,
+            Self::Custom(vocabulary) => return (vocabulary.name, vocabulary.list),
+        };
+        (THEMES[index].0, *THEMES[index].1)
+    }
+}
+
+/// Adjectives and nouns supplied at runtime, usable wherever a [`Theme`] is.
+///
+/// Words given to [`Vocabulary::new`] are copied and kept for the rest of the program (generated
+/// [`NamePair`]s borrow them for `'static`), so vocabularies are meant to be created once, e.g. at
+/// startup. [`define_theme!`] builds one at compile time without allocating.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Vocabulary {
+    name: &'static str,
+    list: WordLists,
+}
+
+// Hashes the name and list sizes only, so vocabularies stay cheap to look up in hash maps;
+// equality still compares every word.
+impl Hash for Vocabulary {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.list.adjectives.len().hash(state);
+        self.list.nouns.len().hash(state);
+    }
+}
+
+impl Vocabulary {
+    /// Validate and store a word list.
+    ///
+    /// Both lists must be non-empty, words must not be blank, there may be at most 2,047 nouns,
+    /// and, as with the built-in themes, no two distinct pairs may render to the same name once
+    /// case and separators are ignored.
+    ///
+    /// The words are copied and leaked, never freed; see the type docs.
+    pub fn new<A, N>(
+        name: &str,
+        adjectives: &[A],
+        nouns: &[N],
+    ) -> Result<Vocabulary, VocabularyError>
+    where
+        A: AsRef<str>,
+        N: AsRef<str>,
+    {
+        let adjectives = checked_words(adjectives, VocabularyError::NoAdjectives)?;
+        let nouns = checked_words(nouns, VocabularyError::NoNouns)?;
+        if nouns.len() > MAX_NOUNS {
+            return Err(VocabularyError::TooManyNouns);
+        }
+        // Checked on the keys themselves, which are lowercased beyond ASCII.
+        let adjective_keys: Vec<String> =
+            adjectives.iter().map(|word| comparison_key(word)).collect();
+        let noun_keys: Vec<String> = nouns.iter().map(|word| comparison_key(word)).collect();
+        let adjective_keys: Vec<&str> = adjective_keys.iter().map(String::as_str).collect();
+        let noun_keys: Vec<&str> = noun_keys.iter().map(String::as_str).collect();
+        if !pairs_are_distinguishable(&adjective_keys, &[&noun_keys]) {
+            return Err(VocabularyError::AmbiguousPairs);
+        }
+        let list = WordLists {
+            adjectives: leak_words(adjectives),
+            nouns: leak_words(nouns),
+        };
+        Ok(Vocabulary {
+            name: Box::leak(name.into()),
+            list,
+        })
+    }
+
+    /// Compile-time counterpart of [`Vocabulary::new`] used by [`define_theme!`]; both lists must
+    /// already be in key order, and words must be ASCII.
+    ///
+    /// # Panics
+    ///
+    /// Panics (a compile error in const context) if the lists break the rules of
+    /// [`Vocabulary::new`].
+    #[doc(hidden)]
+    pub const fn from_static(
+        name: &'static str,
+        adjectives: &'static [&'static str],
+        nouns: &'static [&'static str],
+    ) -> Vocabulary {
+        assert!(!adjectives.is_empty(), "theme has no adjectives");
+        assert!(!nouns.is_empty(), "theme has no nouns");
+        assert!(nouns.len() <= MAX_NOUNS, "theme has more than 2047 nouns");
+        let list = WordLists { adjectives, nouns };
+        assert!(
+            !has_blank_word(adjectives) && !has_blank_word(nouns),
+            "theme contains a blank word"
+        );
+        assert!(
+            all_ascii(&[adjectives, nouns]),
+            "define_theme! words must be ASCII"
+        );
+        assert!(
+            pairs_are_distinguishable(adjectives, &[nouns]),
+            "theme repeats a word or has two pairs that render to the same name"
+        );
+        Vocabulary { name, list }
+    }
+
+    /// The name this vocabulary was created with.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Define a custom theme as a `const` [`Theme`], checked at compile time and stored without any
+/// runtime allocation.
+///
+/// ```
+/// sci_fi_food::define_theme! {
+///     pub HARBOR = "harbor" {
+///         adjectives: ["misty", "brisk", "salty"],
+///         nouns: ["lighthouse", "jetty", "buoy"],
+///     }
+/// }
+///
+/// let mut generator = sci_fi_food::NameGenerator::from_seed(7);
+/// let name = generator.name(HARBOR);
+/// assert!(name.starts_with(|c: char| c.is_uppercase()));
+/// ```
+///
+/// Empty lists, blank or repeated words, non-ASCII words and pairs that render to the same name
+/// are compile errors; use [`Vocabulary::new`] for words beyond ASCII.
+#[macro_export]
+macro_rules! define_theme {
+    (
+        $vis:vis $ident:ident = $name:literal {
+            adjectives: [$($adjective:literal),+ $(,)?],
+            nouns: [$($noun:literal),+ $(,)?] $(,)?
+        }
+    ) => {
+        $vis const $ident: $crate::Theme = {
+            const ADJECTIVES: [&str; [$($adjective),+].len()] =
+                $crate::__sort_words([$($adjective),+]);
+            const NOUNS: [&str; [$($noun),+].len()] = $crate::__sort_words([$($noun),+]);
+            $crate::Theme::Custom($crate::Vocabulary::from_static($name, &ADJECTIVES, &NOUNS))
+        };
+        const _: $crate::Theme = $ident;
+    };
+}
+
+/// A theme chosen at compile time, for [`NameGenerator::words_of`] and friends; usually
+/// implemented by [`theme_functions!`].
+pub trait StaticTheme {
+    const THEME: Theme;
+}
+
+/// Generate a module of inline functions bound to one theme, for hot paths that only ever use
+/// that theme: the functions are monomorphized for it instead of matching on [`Theme`] at
+/// runtime.
+///
+/// ```
+/// sci_fi_food::theme_functions! {
+///     pub mod ship_names = sci_fi_food::Theme::SciFi;
+/// }
+///
+/// let mut generator = sci_fi_food::NameGenerator::from_seed(7);
+/// let pair = ship_names::words(&mut generator);
+/// let name = ship_names::name(&mut generator);
+/// assert!(!ship_names::random_name().is_empty());
+/// ```
+///
+/// The theme can be any constant [`Theme`] in scope of the macro call, including one made with
+/// [`define_theme!`]. The module holds `words`, `name` and `random_name`, plus the
+/// [`StaticTheme`] marker `Selected` for the generic [`NameGenerator::words_of`],
+/// [`NameGenerator::name_of`] and [`random_name_of`].
+#[macro_export]
+macro_rules! theme_functions {
+    ($vis:vis mod $module:ident = $theme:expr $(;)?) => {
+        $vis mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// Marker for the theme these functions are bound to.
+            pub struct Selected;
+
+            impl $crate::StaticTheme for Selected {
+                const THEME: $crate::Theme = $theme;
+            }
+
+            /// Adjective + noun pair from the bound theme.
+            #[inline]
+            pub fn words(generator: &mut $crate::NameGenerator) -> $crate::NamePair {
+                generator.words_of::<Selected>()
+            }
+
+            /// Formatted name from the bound theme.
+            #[inline]
+            pub fn name(generator: &mut $crate::NameGenerator) -> ::std::string::String {
+                generator.name_of::<Selected>()
+            }
+
+            /// Title Case name from the bound theme, drawn from the thread-local generator.
+            #[inline]
+            pub fn random_name() -> ::std::string::String {
+                $crate::random_name_of::<Selected>()
+            }
+        }
+    };
+}
+
+/// Sort words into the key order [`Vocabulary::new`] uses; used by [`define_theme!`].
+#[doc(hidden)]
+pub const fn __sort_words<const N: usize>(mut words: [&'static str; N]) -> [&'static str; N] {
+    let mut sorted = 1;
+    while sorted < N {
+        let mut index = sorted;
+        while index > 0 && key_less_than(words[index].as_bytes(), words[index - 1].as_bytes()) {
+            words.swap(index, index - 1);
+            index -= 1;
+        }
+        sorted += 1;
+    }
+    words
+}
+
+const fn has_blank_word(words: &[&str]) -> bool {
+    let mut index = 0;
+    while index < words.len() {
+        if key_is_empty(words[index].as_bytes(), 0) {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
+/// Trimmed words in key order, rejecting empty lists, blanks and duplicates.
+fn checked_words<W: AsRef<str>>(
+    words: &[W],
+    empty: VocabularyError,
+) -> Result<Vec<String>, VocabularyError> {
+    if words.is_empty() {
+        return Err(empty);
+    }
+    let mut seen = HashSet::with_capacity(words.len());
+    let mut checked = Vec::with_capacity(words.len());
+    for word in words {
+        let word = word.as_ref().trim();
+        let key = comparison_key(word);
+        if key.is_empty() {
+            return Err(VocabularyError::BlankWord);
+        }
+        if !seen.insert(key) {
+            return Err(VocabularyError::DuplicateWord(word.to_string()));
+        }
+        checked.push(word.to_string());
+    }
+    // The order `pairs_are_distinguishable` expects.
+    checked.sort_by_cached_key(|word| comparison_key(word));
+    Ok(checked)
+}
+
+fn leak_words(words: Vec<String>) -> &'static [&'static str] {
+    let words: Vec<&'static str> = words
+        .into_iter()
+        .map(|word| &*Box::leak(word.into_boxed_str()))
+        .collect();
+    Box::leak(words.into_boxed_slice())
+}
+
+/// Why [`Vocabulary::new`] rejected a word list.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VocabularyError {
+    NoAdjectives,
+    NoNouns,
+    /// More than 2,047 nouns.
+    TooManyNouns,
+    /// A word with no letters or digits.
+    BlankWord,
+    /// A word listed twice, possibly with different case or separators.
+    DuplicateWord(String),
+    /// Two distinct pairs join into the same name (`gold` + `enigma` and `golden` + `igma`).
+    AmbiguousPairs,
+}
+
+impl fmt::Display for VocabularyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoAdjectives => f.write_str("word list has no adjectives"),
+            Self::NoNouns => f.write_str("word list has no nouns"),
+            Self::TooManyNouns => f.write_str("word list has more than 2047 nouns"),
+            Self::BlankWord => f.write_str("word list contains a blank word"),
+            Self::DuplicateWord(word) => write!(f, "word `{word}` is listed twice"),
+            Self::AmbiguousPairs => f.write_str("two word pairs render to the same name"),
+        }
+    }
+}
+
+impl std::error::Error for VocabularyError {}
+
+/// Why [`Theme::from_json`], [`Theme::from_toml`] or [`Theme::from_lines`] failed.
+#[derive(Debug)]
+pub enum ThemeLoadError {
+    Io(io::Error),
+    /// Malformed input at `line` (1-based).
+    Syntax {
+        line: usize,
+        message: &'static str,
+    },
+    /// The words themselves were rejected.
+    Vocabulary(VocabularyError),
+}
+
+impl fmt::Display for ThemeLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "cannot read theme: {error}"),
+            Self::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            Self::Vocabulary(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ThemeLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Syntax { .. } => None,
+            Self::Vocabulary(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for ThemeLoadError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<VocabularyError> for ThemeLoadError {
+    fn from(error: VocabularyError) -> Self {
+        Self::Vocabulary(error)
+    }
+}
+
+/// Cursor over the JSON and TOML subsets accepted by [`Theme::from_json`] and
+/// [`Theme::from_toml`]: string arrays keyed by `adjectives` and `nouns`.
+struct Scanner<'a> {
+    text: &'a str,
+    pos: usize,
+    /// Whether `#` starts a comment (TOML).
+    comments: bool,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(text: &'a str, comments: bool) -> Self {
+        Self {
+            text,
+            pos: 0,
+            comments,
+        }
+    }
+
+    fn error(&self, message: &'static str) -> ThemeLoadError {
+        ThemeLoadError::Syntax {
+            line: self.text[..self.pos].matches('\n').count() + 1,
+            message,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_space(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if !(self.comments && trimmed.starts_with('#')) {
+                return;
+            }
+            self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+        }
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_space();
+        self.pos == self.text.len()
+    }
+
+    fn finish(&mut self) -> Result<(), ThemeLoadError> {
+        match self.at_end() {
+            true => Ok(()),
+            false => Err(self.error("unexpected text after the theme")),
+        }
+    }
+
+    fn eat(&mut self, ch: char) -> bool {
+        self.skip_space();
+        let found = self.rest().starts_with(ch);
+        if found {
+            self.pos += ch.len_utf8();
+        }
+        found
+    }
+
+    fn expect(&mut self, ch: char, message: &'static str) -> Result<(), ThemeLoadError> {
+        match self.eat(ch) {
+            true => Ok(()),
+            false => Err(self.error(message)),
+        }
+    }
+
+    /// The list a key fills; only `adjectives` and `nouns` are accepted.
+    fn list_for<'l>(
+        &self,
+        key: &str,
+        adjectives: &'l mut Vec<String>,
+        nouns: &'l mut Vec<String>,
+    ) -> Result<&'l mut Vec<String>, ThemeLoadError> {
+        match key {
+            "adjectives" => Ok(adjectives),
+            "nouns" => Ok(nouns),
+            _ => Err(self.error("expected `adjectives` or `nouns`")),
+        }
+    }
+
+    fn bare_key(&mut self) -> Result<String, ThemeLoadError> {
+        self.skip_space();
+        if self.rest().starts_with(['"', '\'']) {
+            return self.string();
+        }
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(self.error("expected a key"));
+        }
+        let key = &self.rest()[..len];
+        self.pos += len;
+        Ok(key.to_string())
+    }
+
+    fn string_array(&mut self) -> Result<Vec<String>, ThemeLoadError> {
+        self.expect('[', "expected `[`")?;
+        let mut words = Vec::new();
+        while !self.eat(']') {
+            words.push(self.string()?);
+            if !self.eat(',') {
+                self.expect(']', "expected `,` or `]`")?;
+                break;
+            }
+        }
+        Ok(words)
+    }
+
+    /// A double-quoted string with JSON escapes, or (TOML) a single-quoted literal string.
+    fn string(&mut self) -> Result<String, ThemeLoadError> {
+        self.skip_space();
+        if self.comments && self.eat('\'') {
+            let len = self.rest().find(['\'', '\n']).unwrap_or(self.rest().len());
+            let text = self.rest()[..len].to_string();
+            self.pos += len;
+            self.expect('\'', "unterminated string")?;
+            return Ok(text);
+        }
+        self.expect('"', "expected a string")?;
+        let mut text = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((offset, ch)) = chars.next() {
+            match ch {
+                '"' => {
+                    self.pos += offset + 1;
+                    return Ok(text);
+                }
+                '\\' => {
+                    let escaped = match chars.next().map(|(_, ch)| ch) {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some(ch @ ('"' | '\\' | '/')) => ch,
+                        Some('u') => {
+                            let hex: String = chars.by_ref().take(4).map(|(_, ch)| ch).collect();
+                            u32::from_str_radix(&hex, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    text.push(escaped);
+                }
+                '\n' => break,
+                _ => text.push(ch),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+}
+
+/// Named themes registered at runtime, alongside the built-in ones.
+#[derive(Clone, Debug, Default)]
+pub struct ThemeRegistry {
+    custom: HashMap<String, Theme>,
+}
+
+impl ThemeRegistry {
+    /// Create a registry that only knows the built-in themes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `adjectives` and `nouns` under `name`, replacing any custom theme of that name.
+    /// Custom themes take precedence over built-in themes with the same name.
+    pub fn register<A, N>(
+        &mut self,
+        name: &str,
+        adjectives: &[A],
+        nouns: &[N],
+    ) -> Result<Theme, VocabularyError>
+    where
+        A: AsRef<str>,
+        N: AsRef<str>,
+    {
+        let theme = Theme::Custom(Vocabulary::new(name, adjectives, nouns)?);
+        self.custom.insert(name.to_string(), theme);
+        Ok(theme)
+    }
+
+    /// Look up a custom or built-in theme by name.
+    pub fn get(&self, name: &str) -> Option<Theme> {
+        self.custom
+            .get(name)
+            .copied()
+            .or_else(|| Theme::from_name(name))
+    }
+
+    /// Draw a name from the theme called `name`, or `None` if there is no such theme.
+    pub fn name(&self, name: &str, generator: &mut NameGenerator) -> Option<String> {
+        Some(generator.name(self.get(name)?))
+    }
+}
+
+/// Hash attempts [`suggest_renames`] spends looking for a pair whose words are both unused in the
+/// batch before settling for any free pair.
+const SPREAD_ATTEMPTS: u64 = 32;
+
+/// Propose a friendly name from `theme`, rendered with `format`, for every identifier in `ids`,
+/// e.g. to migrate a legacy fleet; returns `(identifier, name)` rows in input order.
+///
+/// Names depend only on the set of identifiers, not their order, so rerunning the migration gives
+/// the same table. No two identifiers share a name (repeated identifiers get the same one), and
+/// names avoid reusing an adjective or noun already in the batch while the theme allows, so
+/// similar identifiers still get easily told apart names.
+///
+/// # Panics
+///
+/// Panics if `ids` holds more distinct identifiers than `theme` has pairs.
+pub fn suggest_renames<S: AsRef<str>>(
+    ids: &[S],
+    theme: Theme,
+    format: Format<'_>,
+) -> Vec<(String, String)> {
+    let list = theme.list();
+    let mut distinct: Vec<&str> = ids.iter().map(AsRef::as_ref).collect();
+    distinct.sort_unstable();
+    distinct.dedup();
+    assert!(
+        distinct.len() <= list.adjectives.len() * list.nouns.len(),
+        "more identifiers than {} names",
+        theme.name()
+    );
+
+    let (mut taken, mut used_words) = (HashSet::new(), HashSet::new());
+    let mut names = HashMap::with_capacity(distinct.len());
+    for id in distinct {
+        let candidate = |attempt: u64| {
+            let mut key = id.as_bytes().to_vec();
+            key.push(0xff);
+            key.extend_from_slice(&attempt.to_le_bytes());
+            hashed_pair(&list, stable_hash(&key))
+        };
+        let pair = (0..SPREAD_ATTEMPTS)
+            .map(candidate)
+            .find(|pair| {
+                !taken.contains(pair)
+                    && !used_words.contains(pair.adjective)
+                    && !used_words.contains(pair.noun)
+            })
+            .or_else(|| {
+                (SPREAD_ATTEMPTS..SPREAD_ATTEMPTS + MAX_DRAW_ATTEMPTS as u64)
+                    .map(candidate)
+                    .find(|pair| !taken.contains(pair))
+            })
+            .or_else(|| {
+                // Nearly every pair is taken: walk the lists for a free one.
+                let (adjectives, nouns) = (list.adjectives.iter(), list.nouns.iter());
+                adjectives
+                    .flat_map(|&adjective| {
+                        nouns.clone().map(move |&noun| NamePair { adjective, noun })
+                    })
+                    .find(|pair| !taken.contains(pair))
+            })
+            .expect("a free pair exists");
+        taken.insert(pair);
+        used_words.extend([pair.adjective, pair.noun]);
+        names.insert(id, pair.render(format));
+    }
+    ids.iter()
+        .map(|id| (id.as_ref().to_string(), names[id.as_ref()].clone()))
+        .collect()
+}
+
+/// Outcome of [`NameGenerator::verify_log`] and [`verify_log`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LogVerdict {
+    /// Every logged pair is the one the generator issues; `entries` pairs were checked.
+    Valid { entries: usize },
+    /// The pair logged on `line` (1-based) is not `expected`, the pair the generator issues
+    /// there, so the log was altered or written by a differently configured generator.
+    Mismatch { line: usize, expected: NamePair },
+}
+
+/// [`NameGenerator::verify_log`] for a log written by an otherwise unconfigured
+/// [`NameGenerator::from_seed`]`(seed)`.
+pub fn verify_log(seed: u64, log: impl BufRead) -> io::Result<LogVerdict> {
+    NameGenerator::from_seed(seed).verify_log(log)
+}
+
+/// One codename per incident key, issued from a unique generator and kept across restarts with
+/// [`Incidents::save`] and [`Incidents::load`].
+#[derive(Clone)]
+pub struct Incidents {
+    generator: NameGenerator,
+    theme: Theme,
+    codenames: HashMap<String, NamePair>,
+}
+
+impl Incidents {
+    /// Issue codenames from `theme` with `generator`, which is switched to
+    /// [`unique`](NameGenerator::unique) mode. Its [`Exhaustion`] policy is not used: codenames
+    /// never repeat, so [`Incidents::codename`] returns `None` once the theme is used up.
+    pub fn new(generator: NameGenerator, theme: Theme) -> Self {
+        Self {
+            generator: generator.unique().on_exhaustion(Exhaustion::NumberSuffix),
+            theme,
+            codenames: HashMap::new(),
+        }
+    }
+
+    /// The codename of incident `key`, issuing a new one the first time the key is seen; `None`
+    /// if `key` is new and every pair of the theme is already taken.
+    pub fn codename(&mut self, key: &str) -> Option<Codename> {
+        if let Some(&pair) = self.codenames.get(key) {
+            return Some(Codename(pair));
+        }
+        let pair = self.generator.words(self.theme);
+        if self.generator.escalation.is_some() {
+            return None;
+        }
+        self.codenames.insert(key.to_string(), pair);
+        Some(Codename(pair))
+    }
+
+    /// The codename already issued for `key`, if any.
+    pub fn get(&self, key: &str) -> Option<Codename> {
+        self.codenames.get(key).copied().map(Codename)
+    }
+
+    /// Write every issued codename as `key<TAB>adjective<TAB>noun` lines, sorted by key.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`] if a key contains a tab or a line break.
+    pub fn save(&self, out: &mut impl io::Write) -> io::Result<()> {
+        let mut keys: Vec<&String> = self.codenames.keys().collect();
+        keys.sort();
+        for key in keys {
+            if key.contains(['\t', '\n', '\r']) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("incident key {key:?} contains a tab or line break"),
+                ));
+            }
+            let pair = self.codenames[key];
+            writeln!(out, "{key}\t{}\t{}", pair.adjective, pair.noun)?;
+        }
+        Ok(())
+    }
+
+    /// Restore codenames written by [`Incidents::save`]; they are blocked in `generator` so new
+    /// incidents never reuse them.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] on a malformed line or a word that is not in
+    /// `theme`.
+    pub fn load(generator: NameGenerator, theme: Theme, input: impl BufRead) -> io::Result<Self> {
+        let mut incidents = Self::new(generator, theme);
+        let list = theme.list();
+        for (index, line) in input.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            let invalid = |message: &str| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: {message}", index + 1),
+                )
+            };
+            let mut fields = line.split('\t');
+            let (Some(key), Some(adjective), Some(noun), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid(
+                    "expected key, adjective and noun separated by tabs",
+                ));
+            };
+            let find = |words: &'static [&'static str], word: &str| {
+                words.iter().copied().find(|candidate| *candidate == word)
+            };
+            let pair = NamePair {
+                adjective: find(list.adjectives, adjective)
+                    .ok_or_else(|| invalid("adjective is not in the theme"))?,
+                noun: find(list.nouns, noun).ok_or_else(|| invalid("noun is not in the theme"))?,
+            };
+            incidents.generator.block(pair);
+            incidents.codenames.insert(key.to_string(), pair);
+        }
+        Ok(incidents)
+    }
+}
+
+/// Incident codename from [`Incidents::codename`], with the variants on-call tooling needs.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Codename(pub NamePair);
+
+impl Codename {
+    /// `Shiny Mango`.
+    pub fn title(&self) -> String {
+        self.0.title_case()
+    }
+
+    /// `inc-shiny-mango`: lowercase ASCII, within Slack's 80-character channel name limit.
+    pub fn slack_channel(&self) -> String {
+        let format = Format::new(Case::Lower, "-")
+            .ascii_only()
+            .with_max_len(76, Overflow::TruncateNoun);
+        format!("inc-{}", self.0.render(format))
+    }
+
+    /// `Incident: Shiny Mango`, for postmortem documents.
+    pub fn doc_title(&self) -> String {
+        format!("Incident: {}", self.title())
+    }
+
+    /// `SHI-MAN`: the first three letters of the adjective and of the noun, for pages and
+    /// tickets. Unlike the full codename it is not guaranteed to be unique.
+    pub fn short_code(&self) -> String {
+        let upper = Format::new(Case::Upper, "").ascii_only();
+        let stem = |word: &str| {
+            render_words(&[word], upper)
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .take(3)
+                .collect::<String>()
+        };
+        format!("{}-{}", stem(self.0.adjective), stem(self.0.noun))
+    }
+}
+
+/// Consistent friendly names for identifiers, e.g. to demo a dataset publicly without exposing
+/// real IDs (see [`Anonymizer::rewrite_csv`]).
+#[derive(Clone)]
+pub struct Anonymizer {
+    generator: NameGenerator,
+    theme: Theme,
+    key: Option<u64>,
+    names: HashMap<String, String>,
+}
+
+impl Anonymizer {
+    /// Name identifiers with pairs from `theme`, drawn by `generator` and rendered in its format.
+    /// An identifier always gets the same name from one anonymizer, and no two identifiers share
+    /// a name.
+    pub fn new(generator: NameGenerator, theme: Theme) -> Self {
+        Self {
+            generator: generator.unique(),
+            theme,
+            key: None,
+            names: HashMap::new(),
+        }
+    }
+
+    /// Derive names from `key` and the identifier instead of drawing them, so every run with the
+    /// same key gives an identifier the same name.
+    ///
+    /// Names stay unique: when two identifiers hash to the same pair, the one seen first keeps it
+    /// and the later one is rehashed. Only those colliding identifiers depend on the order
+    /// identifiers are seen in; feed them in a fixed order (e.g. sorted) to make every name
+    /// reproducible.
+    pub fn keyed(mut self, key: &str) -> Self {
+        self.key = Some(stable_hash(key.as_bytes()));
+        self
+    }
+
+    /// The friendly name of `id`, issuing one the first time `id` is seen.
+    pub fn name(&mut self, id: &str) -> String {
+        if let Some(name) = self.names.get(id) {
+            return name.clone();
+        }
+        let pair = match self.key {
+            Some(key) => self.keyed_pair(key, id),
+            None => self.generator.words(self.theme),
+        };
+        let name = pair.render(self.generator.format);
+        self.names.insert(id.to_string(), name.clone());
+        name
+    }
+
+    /// Hash `key` and `id` to a pair, rehashing until it is one no other identifier has.
+    fn keyed_pair(&mut self, key: u64, id: &str) -> NamePair {
+        let list = self.theme.list();
+        for attempt in 0..MAX_DRAW_ATTEMPTS as u64 {
+            let mut bytes = key.to_le_bytes().to_vec();
+            bytes.extend_from_slice(id.as_bytes());
+            bytes.push(0xff);
+            bytes.extend_from_slice(&attempt.to_le_bytes());
+            let pair = hashed_pair(&list, stable_hash(&bytes));
+            if !self.generator.blocked.contains(&pair) {
+                self.generator.block(pair);
+                return pair;
+            }
+        }
+        panic!("anonymized names cover the whole combination space");
+    }
+
+    /// Copy CSV from `input` to `out`, replacing every non-empty value of the column whose header
+    /// is `column` with its [`name`](Anonymizer::name). Returns the number of data rows.
+    ///
+    /// Blank lines are dropped. Fails with [`io::ErrorKind::InvalidInput`] if there is no such
+    /// column, and with [`io::ErrorKind::InvalidData`] on a row too short to have it.
+    pub fn rewrite_csv(
+        &mut self,
+        mut input: impl BufRead,
+        out: &mut impl io::Write,
+        column: &str,
+    ) -> io::Result<usize> {
+        let Some(header) = read_csv_record(&mut input)? else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "CSV input has no header row",
+            ));
+        };
+        let index = header
+            .iter()
+            .position(|field| field == column)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("CSV header has no column {column:?}"),
+                )
+            })?;
+        write_csv_record(&header, out)?;
+        let mut rows = 0;
+        while let Some(mut record) = read_csv_record(&mut input)? {
+            rows += 1;
+            let Some(value) = record.get_mut(index) else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("row {rows} has no column {column:?}"),
+                ));
+            };
+            if !value.is_empty() {
+                *value = self.name(value);
+            }
+            write_csv_record(&record, out)?;
+        }
+        Ok(rows)
+    }
+}
+
+/// Read one CSV record, which may span lines inside a quoted field; `None` at end of input.
+/// Blank lines between records are skipped.
+fn read_csv_record(input: &mut impl BufRead) -> io::Result<Option<Vec<String>>> {
+    let mut text = String::new();
+    loop {
+        if input.read_line(&mut text)? == 0 {
+            if text.is_empty() {
+                return Ok(None);
+            }
+            break;
+        }
+        if text.trim_end_matches(['\r', '\n']).is_empty() {
+            text.clear();
+            continue;
+        }
+        if text.matches('"').count().is_multiple_of(2) {
+            break;
+        }
+    }
+    let text = text.strip_suffix('\n').unwrap_or(&text);
+    let text = text.strip_suffix('\r').unwrap_or(text);
+    let (mut fields, mut field, mut quoted) = (Vec::new(), String::new(), false);
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(core::mem::take(&mut field)),
+            ch => field.push(ch),
+        }
+    }
+    fields.push(field);
+    Ok(Some(fields))
+}
+
+fn write_csv_record(fields: &[String], out: &mut impl io::Write) -> io::Result<()> {
+    let mut line = String::new();
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            line.push(',');
+        }
+        push_csv_field(field, &mut line);
+    }
+    line.push('\n');
+    out.write_all(line.as_bytes())
+}
+
+/// Allergen classes used to tag the built-in food nouns.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Allergen {
+    Dairy,
+    Egg,
+    Fish,
+    Gluten,
+    /// Tree nuts and peanuts.
+    Nuts,
+    Sesame,
+    /// Crustaceans and molluscs.
+    Shellfish,
+    Soy,
+}
+
+impl Allergen {
+    /// Lowercase name used in exports (e.g. `shellfish`).
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Dairy => "dairy",
+            Self::Egg => "egg",
+            Self::Fish => "fish",
+            Self::Gluten => "gluten",
+            Self::Nuts => "nuts",
+            Self::Sesame => "sesame",
+            Self::Shellfish => "shellfish",
+            Self::Soy => "soy",
+        }
+    }
+
+    const fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// Allergen tags for a built-in food noun; unknown words have none.
+///
+/// Tags are a loose approximation meant for demo data (e.g. `pizza` is gluten + dairy), not
+/// dietary advice.
+pub fn food_allergens(noun: &str) -> &'static [Allergen] {
+    use Allergen::*;
+    match noun {
+        "almond" | "cashew" | "coconut" | "hazelnut" | "peanut" | "pistachio" | "walnut" => &[Nuts],
+        "clams" | "cuttlefish" | "lobster" | "mussels" | "octopus" | "oyster" | "prawn"
+        | "scallop" | "shrimp" | "squid" => &[Shellfish],
+        "amberjack" | "anchovy" | "barracuda" | "bass" | "black cod" | "bluefin" | "bonito"
+        | "butterfish" | "catfish" | "cod" | "eel" | "flounder" | "grouper" | "halibut"
+        | "herring" | "kingfish" | "mackerel" | "mahi mahi" | "marlin" | "perch" | "pike"
+        | "pollock" | "rockfish" | "sablefish" | "salmon steak" | "sardine" | "snapper"
+        | "sole" | "steelhead" | "sturgeon" | "tilapia" | "tuna steak" | "turbot" | "whitefish" => {
+            &[Fish]
+        }
+        "sushi" => &[Fish, Soy],
+        "bagel" | "bread" | "bun" | "cereal" | "couscous" | "dumpling" | "pasta" | "pretzel"
+        | "spaghetti" => &[Gluten],
+        "ramen" => &[Gluten, Soy],
+        "noodle" => &[Gluten, Egg],
+        "croissant" | "pie" | "pizza" | "biscuit" => &[Gluten, Dairy],
+        "brownie" | "cake" | "churro" | "cookie" | "doughnut" | "lasagna" | "muffin"
+        | "pancake" | "waffle" => &[Gluten, Dairy, Egg],
+        "granola" => &[Gluten, Nuts],
+        "caramel" | "fondue" | "risotto" | "sundae" | "toffee" | "yogurt" => &[Dairy],
+        "omelet" => &[Egg, Dairy],
+        "edamame" | "miso" | "soy" => &[Soy],
+        "sesame" => &[Sesame],
+        _ => &[],
+    }
+}
+
+/// Loose cuisine regions used to tag the built-in food nouns.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Cuisine {
+    EastAsian,
+    SoutheastAsian,
+    SouthAsian,
+    MiddleEastern,
+    Mediterranean,
+    European,
+    LatinAmerican,
+    NorthAmerican,
+}
+
+impl Cuisine {
+    /// Snake-case name used in exports (e.g. `east_asian`).
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::EastAsian => "east_asian",
+            Self::SoutheastAsian => "southeast_asian",
+            Self::SouthAsian => "south_asian",
+            Self::MiddleEastern => "middle_eastern",
+            Self::Mediterranean => "mediterranean",
+            Self::European => "european",
+            Self::LatinAmerican => "latin_american",
+            Self::NorthAmerican => "north_american",
+        }
+    }
+
+    const fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// Cuisine regions a built-in food noun is commonly associated with; generic staples have none.
+pub fn food_cuisines(noun: &str) -> &'static [Cuisine] {
+    use Cuisine::*;
+    match noun {
+        "dumpling" | "edamame" | "eel" | "goji" | "jujube" | "kimchi" | "kumquat" | "loquat"
+        | "mandarin" | "miso" | "mochi" | "noodle" | "persimmon" | "ramen" | "satsuma"
+        | "scallion" | "soy" | "sushi" | "wintermelon" | "yumberry" => &[EastAsian],
+        "longan" | "lychee" | "pomelo" => &[EastAsian, SoutheastAsian],
+        "dragonfruit" | "durian" | "jackfruit" | "mangosteen" | "starfruit" | "sugarapple"
+        | "waxapple" => &[SoutheastAsian],
+        "coconut" | "tamarind" => &[SoutheastAsian, SouthAsian],
+        "curry" | "lentil" | "mango" | "okra" => &[SouthAsian],
+        "ginger" => &[SouthAsian, EastAsian],
+        "date" | "falafel" | "pistachio" | "pomegranate" | "sesame" => &[MiddleEastern],
+        "apricot" | "couscous" | "eggplant" | "fig" => &[MiddleEastern, Mediterranean],
+        "anchovy" | "artichoke" | "arugula" | "basil" | "clementine" | "fennel" | "garlic"
+        | "lasagna" | "lemon" | "octopus" | "olive" | "pasta" | "pizza" | "risotto" | "salami"
+        | "sardine" | "spaghetti" | "tomato" | "zucchini" => &[Mediterranean],
+        "beet" | "bilberry" | "biscuit" | "blackcurrant" | "brussels" | "cabbage"
+        | "cloudberry" | "croissant" | "elderberry" | "fondue" | "gooseberry" | "herring"
+        | "lamb" | "leek" | "lingonberry" | "mutton" | "parsnip" | "redcurrant" | "rutabaga"
+        | "sausage" | "sole" | "sturgeon" | "truffle" | "turbot" | "turnip" | "venison"
+        | "waffle" => &[European],
+        "pretzel" => &[European, NorthAmerican],
+        "acai" | "avocado" | "chipotle" | "churro" | "feijoa" | "guava" | "papaya"
+        | "passionfruit" | "pineapple" | "plantain" | "quinoa" | "salsa" | "taco" => {
+            &[LatinAmerican]
+        }
+        "bacon" | "bagel" | "black cod" | "blueberry" | "boysenberry" | "brisket" | "brownie"
+        | "catfish" | "cranberry" | "doughnut" | "huckleberry" | "marionberry" | "marshmallow"
+        | "muffin" | "pancake" | "pie" | "salmonberry" | "squash" | "steak" | "steelhead"
+        | "sundae" | "turkey" => &[NorthAmerican],
+        _ => &[],
+    }
+}
+
+/// How far-fetched the technology behind a sci-fi noun is, from least to most speculative.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TechLevel {
+    /// Real today: astronomy, chemical elements, existing materials and hardware.
+    Contemporary,
+    /// Plausible extrapolations: fusion cores, lunar bases, space elevators.
+    NearFuture,
+    /// Classic space opera: hyperdrives, deflector arrays, transporters.
+    Interstellar,
+    /// Beyond recognizable physics or minds: sentience chips, star forges, living metal.
+    PostSingularity,
+}
+
+impl TechLevel {
+    /// Snake-case name used in exports (e.g. `near_future`).
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Contemporary => "contemporary",
+            Self::NearFuture => "near_future",
+            Self::Interstellar => "interstellar",
+            Self::PostSingularity => "post_singularity",
+        }
+    }
+}
+
+/// Tech level of a built-in sci-fi noun; anything untagged counts as [`TechLevel::Contemporary`].
+pub fn scifi_tech_level(noun: &str) -> TechLevel {
+    use TechLevel::*;
+    match noun {
+        "ablative plating"
+        | "ai nexus"
+        | "android"
+        | "atmosphere processor"
+        | "biodome"
+        | "bioalloy"
+        | "climate array"
+        | "comms array"
+        | "cryosleep pod"
+        | "cyberpunk"
+        | "cyborg"
+        | "defense grid"
+        | "domed city"
+        | "exosuit"
+        | "firewall grid"
+        | "fusion"
+        | "fusion core"
+        | "fusion lab"
+        | "hab pod"
+        | "heuristic core"
+        | "hydroponics bay"
+        | "hyperalloy"
+        | "ion core"
+        | "laser cannon"
+        | "logic node"
+        | "lunar base"
+        | "maintenance drone"
+        | "mass driver"
+        | "mind control"
+        | "mining colony"
+        | "nano armor"
+        | "nanoglass"
+        | "nanosteel"
+        | "neural core"
+        | "neutrino scanner"
+        | "orbital platform"
+        | "plasma battery"
+        | "plasteel"
+        | "power conduit"
+        | "predictive module"
+        | "quantum array"
+        | "quantum glass"
+        | "quantum link"
+        | "relay tower"
+        | "robocop"
+        | "robodog"
+        | "scanner pod"
+        | "sensor visor"
+        | "signal booster"
+        | "smart metal"
+        | "solar sail"
+        | "space colony"
+        | "space elevator"
+        | "survival pod"
+        | "terra farm"
+        | "terraform rig"
+        | "transparent aluminum"
+        | "weather tower" => NearFuture,
+        "adamantium" | "antimatter cell" | "battle shield" | "beskar" | "blaster" | "carbonite"
+        | "cloaking mesh" | "cruiser" | "deathstar" | "deflector array" | "dilithium"
+        | "durasteel" | "element zero" | "energon" | "grav boots" | "gravity anchor"
+        | "gravity hub" | "hyperdrive" | "inertial damper" | "ion cannon" | "kyber"
+        | "light speed" | "mithril" | "mothership" | "nth metal" | "orbital ring"
+        | "orichalcum" | "planetfall" | "portal" | "shield harmonics" | "speeder"
+        | "star cruiser" | "star gate" | "star metal" | "starbase" | "starship"
+        | "stellar reactor" | "subspace relay" | "tachyon capacitor" | "terraform dome"
+        | "transporter" | "tricorder" | "tritanium" | "ufo" | "unobtanium" | "valyrian steel"
+        | "vibranium" | "warp" | "wormhole" => Interstellar,
+        "astral plane" | "energy matrix" | "living metal" | "neutronium" | "positronic brain"
+        | "sentience chip" | "star forge" => PostSingularity,
+        _ => Contemporary,
+    }
+}
+
+/// Broad kind of thing a built-in food or sci-fi noun names, for grouping generated names.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Category {
+    Fruit,
+    Vegetable,
+    /// Nuts and seeds.
+    Nut,
+    /// Fish and shellfish.
+    Seafood,
+    Meat,
+    /// Breads, pastas and grains.
+    Grain,
+    /// Sweets and baked treats.
+    Dessert,
+    /// Prepared dishes such as curry or sushi.
+    Dish,
+    /// Herbs, spices and condiments.
+    Seasoning,
+    /// Spacecraft of any size, from probes to motherships.
+    Vessel,
+    /// Stations, bases and settlements, including their bays and decks.
+    Station,
+    /// Astronauts, androids and other crew members.
+    Crew,
+    /// Stars, planets and other astronomical objects and phenomena.
+    Celestial,
+    /// Devices, weapons and equipment.
+    Tech,
+    /// Elements, alloys, gems and other materials.
+    Material,
+}
+
+impl Category {
+    /// Every category, food ones first.
+    pub const ALL: [Category; 15] = [
+        Self::Fruit,
+        Self::Vegetable,
+        Self::Nut,
+        Self::Seafood,
+        Self::Meat,
+        Self::Grain,
+        Self::Dessert,
+        Self::Dish,
+        Self::Seasoning,
+        Self::Vessel,
+        Self::Station,
+        Self::Crew,
+        Self::Celestial,
+        Self::Tech,
+        Self::Material,
+    ];
+
+    const fn bit(self) -> u16 {
+        1 << self as u16
+    }
+
+    /// Lowercase name (e.g. `seafood`).
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Fruit => "fruit",
+            Self::Vegetable => "vegetable",
+            Self::Nut => "nut",
+            Self::Seafood => "seafood",
+            Self::Meat => "meat",
+            Self::Grain => "grain",
+            Self::Dessert => "dessert",
+            Self::Dish => "dish",
+            Self::Seasoning => "seasoning",
+            Self::Vessel => "vessel",
+            Self::Station => "station",
+            Self::Crew => "crew",
+            Self::Celestial => "celestial",
+            Self::Tech => "tech",
+            Self::Material => "material",
+        }
+    }
+
+    /// Look up a category by its [`Category::name`].
+    pub fn from_name(name: &str) -> Option<Category> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.name() == name)
+    }
+
+    /// Built-in food and sci-fi nouns in this category, in list order.
+    pub fn nouns(self) -> impl Iterator<Item = &'static str> {
+        FOOD_WORDS
+            .nouns
+            .iter()
+            .chain(SCIFI_WORDS.nouns)
+            .copied()
+            .filter(move |noun| noun_category(noun) == Some(self))
+    }
+}
+
+/// Whether `noun` is in one of the categories set in the `categories` bit mask (empty = all).
+fn in_categories(categories: u16, noun: &str) -> bool {
+    categories == 0 || noun_category(noun).is_some_and(|category| categories & category.bit() != 0)
+}
+
+/// Category of a built-in food or sci-fi noun.
+///
+/// Every food noun has one; abstract sci-fi nouns (`warp`, `enigma`) and words from other themes
+/// have none.
+pub fn noun_category(noun: &str) -> Option<Category> {
+    use Category::*;
+    match noun {
+        "acai" | "apple" | "apricot" | "avocado" | "banana" | "bilberry" | "blackberry"
+        | "blackcurrant" | "blueberry" | "boysenberry" | "breadfruit" | "cantaloupe" | "cherry"
+        | "clementine" | "cloudberry" | "coconut" | "cranberry" | "currant" | "date"
+        | "dewberry" | "dragonfruit" | "durian" | "elderberry" | "feijoa" | "fig"
+        | "fingerlime" | "goji" | "gooseberry" | "grape" | "grapefruit" | "guava" | "honeydew"
+        | "huckleberry" | "jackfruit" | "jujube" | "kiwi" | "kiwifruit" | "kumquat" | "lemon"
+        | "lime" | "lingonberry" | "longan" | "loquat" | "lychee" | "mandarin" | "mango"
+        | "mangosteen" | "marionberry" | "miracleberry" | "mulberry" | "nectarine" | "olive"
+        | "orange" | "papaya" | "passionfruit" | "peach" | "pear" | "persimmon" | "pineapple"
+        | "plantain" | "plum" | "pomegranate" | "pomelo" | "prune" | "raisin" | "raspberry"
+        | "redcurrant" | "salmonberry" | "satsuma" | "starfruit" | "strawberry" | "sugarapple"
+        | "tamarind" | "tangerine" | "watermelon" | "waxapple" | "wintermelon" | "youngberry"
+        | "yumberry" | "zinfandel" => Some(Fruit),
+        "artichoke" | "arugula" | "asparagus" | "beet" | "broccoli" | "broccolini" | "brussels"
+        | "cabbage" | "carrot" | "cauliflower" | "celery" | "chard" | "collard" | "cucumber"
+        | "edamame" | "eggplant" | "fennel" | "garlic" | "kale" | "leek" | "lentil" | "lettuce"
+        | "okra" | "onion" | "parsnip" | "pickle" | "potato" | "radish" | "rutabaga"
+        | "scallion" | "shallot" | "spinach" | "squash" | "tomato" | "truffle" | "turnip"
+        | "yam" | "zucchini" => Some(Vegetable),
+        "almond" | "cashew" | "hazelnut" | "peanut" | "pistachio" | "sesame" | "walnut" => {
+            Some(Nut)
+        }
+        "amberjack" | "anchovy" | "barracuda" | "bass" | "black cod" | "bluefin" | "bonito"
+        | "butterfish" | "catfish" | "clams" | "cod" | "cuttlefish" | "eel" | "flounder"
+        | "grouper" | "halibut" | "herring" | "kingfish" | "lobster" | "mackerel" | "mahi mahi"
+        | "marlin" | "mussels" | "octopus" | "oyster" | "perch" | "pike" | "pollock" | "prawn"
+        | "rockfish" | "sablefish" | "salmon steak" | "sardine" | "scallop" | "shrimp"
+        | "snapper" | "sole" | "squid" | "steelhead" | "sturgeon" | "tilapia" | "tuna steak"
+        | "turbot" | "whitefish" => Some(Seafood),
+        "bacon" | "beef" | "brisket" | "chicken" | "duck" | "ham" | "lamb" | "mutton" | "pork"
+        | "salami" | "sausage" | "steak" | "turkey" | "veal" | "venison" => Some(Meat),
+        "bagel" | "biscuit" | "bread" | "bun" | "cereal" | "couscous" | "croissant" | "granola"
+        | "noodle" | "pasta" | "pretzel" | "quinoa" | "spaghetti" => Some(Grain),
+        "brownie" | "cake" | "candy" | "caramel" | "churro" | "cookie" | "doughnut" | "jelly"
+        | "marshmallow" | "mochi" | "muffin" | "pancake" | "pie" | "sorbet" | "sundae"
+        | "toffee" | "waffle" | "yogurt" => Some(Dessert),
+        "curry" | "dumpling" | "falafel" | "fondue" | "kimchi" | "lasagna" | "omelet" | "pizza"
+        | "ramen" | "risotto" | "stew" | "sushi" | "taco" => Some(Dish),
+        "basil" | "chipotle" | "ginger" | "honey" | "miso" | "nutmeg" | "pepper" | "salsa"
+        | "soy" => Some(Seasoning),
+        "capsule" | "cruiser" | "deathstar" | "deep space probe" | "falcon" | "hovercraft"
+        | "mothership" | "orbiter" | "probe" | "rocket" | "satellite" | "ship" | "shuttle"
+        | "space probe" | "speeder" | "star cruiser" | "starship" | "survival pod" | "ufo" => {
+            Some(Vessel)
+        }
+        "biodome" | "cargo bay" | "citadel" | "docking tube" | "domed city" | "fusion lab"
+        | "hab pod" | "hydroponics bay" | "launchpad" | "lunar base" | "mining colony"
+        | "observation deck" | "observation dome" | "observatory" | "orbital platform"
+        | "orbital ring" | "outpost" | "reactor bay" | "relay tower" | "space colony"
+        | "space station" | "starbase" | "station" | "terra farm" | "terraform dome"
+        | "weather tower" => Some(Station),
+        "android" | "astronaut" | "cyborg" | "droid" | "pioneer" | "ranger" | "robocop"
+        | "robodog" | "scout" | "wayfinder" | "xenobot" => Some(Crew),
+        "asteroid"
+        | "asteroid belt"
+        | "aurora"
+        | "binary star"
+        | "black hole"
+        | "blue giant"
+        | "comet"
+        | "constellation"
+        | "cosmic dust"
+        | "cosmic ray"
+        | "cosmos"
+        | "dark energy"
+        | "dark matter"
+        | "deep space"
+        | "dwarf planet"
+        | "eclipse"
+        | "event horizon"
+        | "exoplanet"
+        | "galaxy"
+        | "gamma ray"
+        | "gas giant"
+        | "gaseous mass"
+        | "globular cluster"
+        | "gravity well"
+        | "heliosphere"
+        | "hypergiant"
+        | "ice giant"
+        | "interstellar medium"
+        | "ion storm"
+        | "kuiper belt"
+        | "magnetar"
+        | "magnetosphere"
+        | "meteor"
+        | "meteor shower"
+        | "meteor storm"
+        | "meteorite"
+        | "nebula"
+        | "nova"
+        | "open cluster"
+        | "photon belt"
+        | "planetary nebula"
+        | "protoplanet"
+        | "protostar"
+        | "pulsar"
+        | "quasar"
+        | "red dwarf"
+        | "red giant"
+        | "ring system"
+        | "rogue planet"
+        | "singularity"
+        | "solar flare"
+        | "solar wind"
+        | "solstice"
+        | "star"
+        | "star cluster"
+        | "starlight"
+        | "stellar nursery"
+        | "supergiant"
+        | "supernova"
+        | "terrestrial planet"
+        | "triple star"
+        | "white dwarf"
+        | "wormhole"
+        | "zenith"
+        | "zodiac" => Some(Celestial),
+        "ablative plating"
+        | "ai nexus"
+        | "antimatter cell"
+        | "aperture"
+        | "atmosphere processor"
+        | "battle shield"
+        | "beacon"
+        | "blaster"
+        | "climate array"
+        | "cloaking mesh"
+        | "comms array"
+        | "countermeasure pack"
+        | "cryosleep pod"
+        | "data vault"
+        | "defense grid"
+        | "deflector array"
+        | "emergency beacon"
+        | "encryption node"
+        | "energy matrix"
+        | "engine"
+        | "eva suit"
+        | "exosuit"
+        | "firewall grid"
+        | "fusion core"
+        | "geothermal tap"
+        | "grav boots"
+        | "gravity anchor"
+        | "gravity hub"
+        | "heuristic core"
+        | "hyperdrive"
+        | "inertial damper"
+        | "ion cannon"
+        | "ion core"
+        | "jetpack"
+        | "laser cannon"
+        | "logic node"
+        | "mainframe cluster"
+        | "maintenance drone"
+        | "mass driver"
+        | "module"
+        | "nano armor"
+        | "neural core"
+        | "neutrino scanner"
+        | "plasma battery"
+        | "portal"
+        | "positronic brain"
+        | "power conduit"
+        | "predictive module"
+        | "quantum array"
+        | "quantum link"
+        | "radio telescope"
+        | "reactor"
+        | "rebreather"
+        | "scanner pod"
+        | "security firewall"
+        | "sensor visor"
+        | "sentience chip"
+        | "signal booster"
+        | "solar sail"
+        | "space elevator"
+        | "space telescope"
+        | "star forge"
+        | "star gate"
+        | "stellar reactor"
+        | "subspace relay"
+        | "tachyon capacitor"
+        | "telemetry drone"
+        | "terraform rig"
+        | "thruster"
+        | "transponder"
+        | "transporter"
+        | "tricorder" => Some(Tech),
+        "adamantium"
+        | "aerogel"
+        | "alloy"
+        | "aluminum"
+        | "amethyst"
+        | "argon"
+        | "arsenic"
+        | "barium"
+        | "beryllium"
+        | "beskar"
+        | "bioalloy"
+        | "bioplastic"
+        | "bismuth"
+        | "boron"
+        | "bromine"
+        | "cadmium"
+        | "calcium"
+        | "carbon"
+        | "carbon fiber"
+        | "carbon steel"
+        | "carbonite"
+        | "ceramic"
+        | "cesium"
+        | "chlorine"
+        | "chromium"
+        | "cobalt"
+        | "copper"
+        | "deuterium"
+        | "diamond"
+        | "dilithium"
+        | "durasteel"
+        | "electrum"
+        | "element zero"
+        | "emerald"
+        | "energon"
+        | "ferrite"
+        | "fiber optic"
+        | "fluorine"
+        | "fused quartz"
+        | "gallium"
+        | "glass"
+        | "gold"
+        | "graphene"
+        | "graphite"
+        | "helium"
+        | "helium-3"
+        | "hydrogen"
+        | "hyperalloy"
+        | "ingot"
+        | "iodine"
+        | "iron"
+        | "jade"
+        | "kevlar"
+        | "krypton"
+        | "kyber"
+        | "lead"
+        | "liquid metal"
+        | "lithium"
+        | "living metal"
+        | "magnesium"
+        | "manganese"
+        | "memory metal"
+        | "mercury"
+        | "meteoric iron"
+        | "mithril"
+        | "moonstone"
+        | "mylar"
+        | "nanofiber"
+        | "nanoglass"
+        | "nanosteel"
+        | "nanotube"
+        | "neon"
+        | "neutronium"
+        | "nickel"
+        | "nitrogen"
+        | "nth metal"
+        | "obsidian"
+        | "onyx"
+        | "opal"
+        | "orichalcum"
+        | "oxygen"
+        | "palladium"
+        | "phosphorus"
+        | "plasteel"
+        | "platinum"
+        | "plutonium"
+        | "polymer"
+        | "potassium"
+        | "quantum glass"
+        | "quartz"
+        | "radium"
+        | "radon"
+        | "ruby"
+        | "sapphire"
+        | "silicon"
+        | "silver"
+        | "smart metal"
+        | "sodium"
+        | "stainless steel"
+        | "star metal"
+        | "strontium"
+        | "sulfur"
+        | "sunstone"
+        | "superalloy"
+        | "superconductor"
+        | "tempered glass"
+        | "thorium"
+        | "tin"
+        | "titanium"
+        | "titanium alloy"
+        | "topaz"
+        | "transparent aluminum"
+        | "tritanium"
+        | "tritium"
+        | "tungsten"
+        | "unobtanium"
+        | "uranium"
+        | "valyrian steel"
+        | "vibranium"
+        | "xenon"
+        | "zinc"
+        | "zircon" => Some(Material),
+        _ => None,
+    }
+}
+
+/// Broad category of a shared adjective, for [`NameGenerator::only_adjective_categories`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AdjectiveCategory {
+    /// Colors and shades (`crimson`, `teal`).
+    Color,
+    /// Heat and cold (`frosty`, `toasty`).
+    Temperature,
+    /// Feel and mouthfeel (`crunchy`, `silky`).
+    Texture,
+    /// Moods and temperaments (`cheerful`, `grumpy`).
+    Mood,
+    /// Speed (`swift`, `zippy`).
+    Speed,
+}
+
+impl AdjectiveCategory {
+    /// Every adjective category.
+    pub const ALL: [AdjectiveCategory; 5] = [
+        Self::Color,
+        Self::Temperature,
+        Self::Texture,
+        Self::Mood,
+        Self::Speed,
+    ];
+
+    const fn bit(self) -> u16 {
+        1 << self as u16
+    }
+
+    /// Lowercase name (e.g. `color`).
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Color => "color",
+            Self::Temperature => "temperature",
+            Self::Texture => "texture",
+            Self::Mood => "mood",
+            Self::Speed => "speed",
+        }
+    }
+
+    /// Look up a category by its [`AdjectiveCategory::name`].
+    pub fn from_name(name: &str) -> Option<AdjectiveCategory> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.name() == name)
+    }
+
+    /// Shared adjectives in this category, in list order.
+    pub fn adjectives(self) -> impl Iterator<Item = &'static str> {
+        ADJECTIVES
+            .iter()
+            .copied()
+            .filter(move |adjective| adjective_category(adjective) == Some(self))
+    }
+}
+
+/// Whether `adjective` is in one of the categories set in the `categories` bit mask (empty = all).
+fn in_adjective_categories(categories: u16, adjective: &str) -> bool {
+    categories == 0
+        || adjective_category(adjective).is_some_and(|category| categories & category.bit() != 0)
+}
+
+/// Category of a shared adjective.
+///
+/// Adjectives that fit none of the categories (`ancient`, `atomic`) have none.
+pub fn adjective_category(adjective: &str) -> Option<AdjectiveCategory> {
+    use AdjectiveCategory::*;
+    match adjective {
+        "amber" | "aqua" | "aquamarine" | "azure" | "beige" | "black" | "blue" | "bronze"
+        | "brown" | "caramel" | "cobalt" | "copper" | "coral" | "cream" | "crimson" | "ebony"
+        | "emerald" | "gold" | "golden" | "gray" | "green" | "hazel" | "indigo" | "ivory"
+        | "lavender" | "lemon" | "lime" | "magenta" | "maroon" | "navy" | "ochre" | "olive"
+        | "orange" | "pearl" | "pink" | "plum" | "purple" | "red" | "rosy" | "ruby" | "saffron"
+        | "scarlet" | "sepia" | "silver" | "teal" | "turquoise" | "violet" | "white" | "yellow" => {
+            Some(Color)
+        }
+        "arctic" | "balmy" | "blazing" | "chilly" | "cold" | "cool" | "fiery" | "frosty"
+        | "hot" | "icy" | "lukewarm" | "polar" | "scalding" | "steamy" | "sweltering"
+        | "toasty" | "tropical" | "warm" | "wintry" => Some(Temperature),
+        "brittle" | "chewy" | "creamy" | "crisp" | "crumbly" | "crunchy" | "crusty" | "downy"
+        | "feathery" | "flaky" | "fluffy" | "glossy" | "gooey" | "gritty" | "mushy" | "oozy"
+        | "polished" | "sandy" | "silken" | "silky" | "sleek" | "slippery" | "smooth" | "soggy"
+        | "sticky" | "syrupy" | "tender" | "wrinkly" => Some(Texture),
+        "agreeable" | "angry" | "anxious" | "bashful" | "blissful" | "calm" | "candid"
+        | "cheerful" | "cheery" | "content" | "cranky" | "curious" | "dreamy" | "eager"
+        | "excited" | "exuberant" | "fearless" | "friendly" | "gleeful" | "glum" | "grateful"
+        | "grumpy" | "guilty" | "happy" | "hopeful" | "jittery" | "jovial" | "joyful"
+        | "kindly" | "lonely" | "melancholy" | "mellow" | "merry" | "moody" | "nervous"
+        | "optimistic" | "peaceful" | "perky" | "playful" | "pleased" | "proud" | "restless"
+        | "sad" | "serene" | "shy" | "silly" | "sincere" | "sleepy" | "smug" | "tense"
+        | "thoughtful" | "upbeat" | "upset" | "whimsical" | "wistful" | "worried" | "zealous" => {
+            Some(Mood)
+        }
+        "brisk" | "dashing" | "fleet" | "quick" | "rapid" | "snappy" | "speedy" | "sprinting"
+        | "swift" | "zippy" => Some(Speed),
+        _ => None,
+    }
+}
+
+/// Shortest and longest name from `list` in characters (see [`ThemeStats::name_lengths`]).
+fn name_lengths(list: &WordLists, format: Format<'_>) -> (usize, usize) {
+    let format = Format {
+        max_len: None,
+        joiner: None,
+        ..format
+    };
+    let range = |words: &[&str]| {
+        words
+            .iter()
+            .map(|word| render_words(&[word], format).chars().count())
+            .fold((usize::MAX, 0), |(min, max), len| {
+                (min.min(len), max.max(len))
+            })
+    };
+    let (adjective, noun) = (range(list.adjectives), range(list.nouns));
+    let mut separator = format.separator.chars().count();
+    if format.order == WordOrder::Inverted {
+        separator += 1;
+    }
+    (
+        adjective.0 + separator + noun.0,
+        adjective.1 + separator + noun.1,
+    )
+}
+
+/// Size of every built-in theme's combination space, in [`export_vocabulary`] theme order.
+pub fn theme_stats() -> Vec<ThemeStats> {
+    THEMES
+        .iter()
+        .map(|(theme, list)| ThemeStats { theme, list })
+        .collect()
+}
+
+/// Word count and random suffix that give a theme's names a required entropy within a length
+/// limit, from [`plan_entropy`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EntropyPlan {
+    /// Words per name: 2, or 3 with an extra leading adjective
+    /// (see [`NameGenerator::three_words`]).
+    pub words: usize,
+    /// Characters in the random suffix; 0 when the words alone are enough.
+    pub suffix_len: usize,
+    pub alphabet: SuffixAlphabet,
+    /// Bits of entropy per name, words and suffix together.
+    pub bits: f64,
+    /// Longest possible name in characters.
+    pub max_len: usize,
+}
+
+impl EntropyPlan {
+    /// Configure `generator` with the planned word count and
+    /// [random suffix](NameGenerator::with_random_suffix).
+    pub fn apply(&self, generator: NameGenerator) -> NameGenerator {
+        let generator = if self.words == 3 {
+            generator.three_words()
+        } else {
+            generator
+        };
+        generator.with_random_suffix(self.suffix_len, self.alphabet)
+    }
+}
+
+/// Plan names from `theme` rendered with `format` that carry at least `bits` bits of entropy and
+/// are at most `max_len` characters long (prefixes and suffixes set on the generator not
+/// included). `None` if no plan fits.
+///
+/// Words alone are preferred: two if the pair is enough, else three if an extra adjective is.
+/// Otherwise the plan tops the words up with a random suffix, using the first of digits, hex,
+/// lowercase letters and Crockford base32 (ever denser, ever harder to read out) whose suffix
+/// fits, with two words before three.
+pub fn plan_entropy(
+    theme: Theme,
+    format: Format<'_>,
+    bits: f64,
+    max_len: usize,
+) -> Option<EntropyPlan> {
+    let list = theme.list();
+    let pair_bits = ((list.adjectives.len() * list.nouns.len()) as f64).log2();
+    let (_, pair_len) = name_lengths(&list, format);
+    // The extra adjective of a three-word name differs from the pair's.
+    let extra_bits = ((ADJECTIVES.len() - 1) as f64).log2();
+    let extra_len = ADJECTIVES
+        .iter()
+        .map(|adjective| render_words(&[adjective], format).chars().count())
+        .max()
+        .unwrap_or(0)
+        + format.separator.chars().count();
+    let plans = [
+        (2, pair_bits, pair_len),
+        (3, pair_bits + extra_bits, pair_len + extra_len),
+    ]
+    .into_iter()
+    .flat_map(|(words, word_bits, words_len)| {
+        [
+            SuffixAlphabet::Digits,
+            SuffixAlphabet::Hex,
+            SuffixAlphabet::Lowercase,
+            SuffixAlphabet::Crockford,
+        ]
+        .into_iter()
+        .map(move |alphabet| {
+            let per_char = (alphabet.chars().len() as f64).log2();
+            let suffix_len = ((bits - word_bits) / per_char).ceil().max(0.0) as usize;
+            let separator = if suffix_len > 0 {
+                format.separator.chars().count()
+            } else {
+                0
+            };
+            EntropyPlan {
+                words,
+                suffix_len,
+                alphabet,
+                bits: word_bits + suffix_len as f64 * per_char,
+                max_len: words_len + separator + suffix_len,
+            }
+        })
+    })
+    .filter(|plan| plan.max_len <= max_len)
+    .collect::<Vec<_>>();
+    plans
+        .iter()
+        .find(|plan| plan.suffix_len == 0)
+        .or(plans.first())
+        .copied()
+}
+
+/// Version of the JSON document produced by [`export_vocabulary`].
+pub const VOCABULARY_SCHEMA_VERSION: u32 = 2;
+
+/// Export every built-in word together with its tags as a JSON document.
+///
+/// The schema (version [`VOCABULARY_SCHEMA_VERSION`]) is:
+///
+/// ```text
+/// {
+///   "schema_version": 2,
+///   "crate_version": "0.1.0",
+///   "adjectives": [
+///     { "word": "acidic", "category": null },
+///     { "word": "amber", "category": "color" },
+///     ...
+///   ],
+///   "themes": [
+///     {
+///       "name": "food",
+///       "nouns": [
+///         { "word": "almond", "category": "nut", "allergens": ["nuts"], "cuisines": [] },
+///         ...
+///       ]
+///     },
+///     {
+///       "name": "scifi",
+///       "nouns": [{ "word": "warp", "category": null, "tech_level": "interstellar" }, ...]
+///     },
+///     { "name": "mineral", "nouns": [{ "word": "agate", "category": null }, ...] },
+///     ...
+///   ]
+/// }
+/// ```
+///
+/// Every word carries its [`adjective_category`] or [`noun_category`] name as `category` (`null`
+/// when it has none). Food and hybrid nouns also carry `allergens` and `cuisines` arrays and
+/// sci-fi nouns a `tech_level`. Themes that do not use the shared adjectives (`hybrid`) list their
+/// own in an `adjectives` array. Words appear in list order, so the document is stable for a given
+/// crate version.
+pub fn export_vocabulary() -> String {
+    let mut json = String::with_capacity(64 * 1024);
+    json.push_str("{\n  \"schema_version\": ");
+    json.push_str(&VOCABULARY_SCHEMA_VERSION.to_string());
+    json.push_str(",\n  \"crate_version\": ");
+    push_json_string(env!("CARGO_PKG_VERSION"), &mut json);
+    json.push_str(",\n  \"adjectives\": [");
+    push_json_adjectives(ADJECTIVES, "\n    ", &mut json);
+    json.push_str("\n  ],\n  \"themes\": [");
+    for (theme_index, (name, list)) in THEMES.iter().enumerate() {
+        if theme_index > 0 {
+            json.push(',');
+        }
+        json.push_str("\n    {\"name\": ");
+        push_json_string(name, &mut json);
+        // Hybrid is the only built-in theme with its own adjectives.
+        if *name == "hybrid" {
+            json.push_str(", \"adjectives\": [");
+            push_json_adjectives(list.adjectives, "\n      ", &mut json);
+            json.push_str("\n    ]");
+        }
+        json.push_str(", \"nouns\": [");
+        for (index, noun) in list.nouns.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str("\n      {\"word\": ");
+            push_json_string(noun, &mut json);
+            json.push_str(", \"category\": ");
+            push_json_optional(noun_category(noun).map(Category::name), &mut json);
+            match *name {
+                "food" | "hybrid" => {
+                    json.push_str(", \"allergens\": [");
+                    push_json_names(food_allergens(noun).iter().map(|a| a.name()), &mut json);
+                    json.push_str("], \"cuisines\": [");
+                    push_json_names(food_cuisines(noun).iter().map(|c| c.name()), &mut json);
+                    json.push(']');
+                }
+                "scifi" => {
+                    json.push_str(", \"tech_level\": ");
+                    push_json_string(scifi_tech_level(noun).name(), &mut json);
+                }
+                _ => {}
+            }
+            json.push('}');
+        }
+        json.push_str("\n    ]}");
+    }
+    json.push_str("\n  ]\n}\n");
+    json
+}
+
+fn push_json_names<'a>(names: impl Iterator<Item = &'a str>, json: &mut String) {
+    for (index, name) in names.enumerate() {
+        if index > 0 {
+            json.push_str(", ");
+        }
+        push_json_string(name, json);
+    }
+}
+
+/// `adjectives` as `{"word": ..., "category": ...}` objects, each on a line starting with `indent`.
+fn push_json_adjectives(adjectives: &[&str], indent: &str, json: &mut String) {
+    for (index, adjective) in adjectives.iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+        json.push_str(indent);
+        json.push_str("{\"word\": ");
+        push_json_string(adjective, json);
+        json.push_str(", \"category\": ");
+        push_json_optional(
+            adjective_category(adjective).map(AdjectiveCategory::name),
+            json,
+        );
+        json.push('}');
+    }
+}
+
+fn push_json_optional(text: Option<&str>, json: &mut String) {
+    match text {
+        Some(text) => push_json_string(text, json),
+        None => json.push_str("null"),
+    }
+}
+
+fn push_json_string(text: &str, json: &mut String) {
+    json.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            ch if u32::from(ch) < 0x20 => json.push_str(&format!("\\u{:04x}", u32::from(ch))),
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+}
+
+/// Value held by a column of the rows produced by [`sql_fixtures`] and [`csv_fixtures`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FixtureColumn {
+    /// Row number, starting at 1.
+    Id,
+    /// Generated name in Title Case, unique within one call.
+    Name,
+    /// Theme name (e.g. `food`).
+    Theme,
+    /// Seed the rows were generated from, so they can be regenerated.
+    Seed,
+}
+
+/// `INSERT INTO table (...) VALUES ...;` statement seeding `rows` rows, with one column per
+/// `(column name, value)` entry of `schema` in order.
+///
+/// Names come from a [`unique`](NameGenerator::unique) generator seeded with `seed`, so the same
+/// arguments always produce the same statement. `table` and the column names are written as
+/// given; text values are single-quoted. Returns an empty string when `rows` is zero.
+pub fn sql_fixtures(
+    table: &str,
+    schema: &[(&str, FixtureColumn)],
+    theme: Theme,
+    seed: u64,
+    rows: usize,
+) -> String {
+    if rows == 0 {
+        return String::new();
+    }
+    let columns: Vec<&str> = schema.iter().map(|(name, _)| *name).collect();
+    let mut sql = format!("INSERT INTO {table} ({}) VALUES", columns.join(", "));
+    for (index, values) in fixture_rows(schema, theme, seed, rows).enumerate() {
+        sql.push_str(if index == 0 { "\n  (" } else { ",\n  (" });
+        for (column, (value, (_, kind))) in values.iter().zip(schema).enumerate() {
+            if column > 0 {
+                sql.push_str(", ");
+            }
+            match kind {
+                FixtureColumn::Id | FixtureColumn::Seed => sql.push_str(value),
+                FixtureColumn::Name | FixtureColumn::Theme => {
+                    sql.push('\'');
+                    sql.push_str(&value.replace('\'', "''"));
+                    sql.push('\'');
+                }
+            }
+        }
+        sql.push(')');
+    }
+    sql.push_str(";\n");
+    sql
+}
+
+/// CSV with a header row of the column names in `schema` followed by `rows` rows, generated
+/// like [`sql_fixtures`]. Fields containing a comma, quote or line break are quoted.
+pub fn csv_fixtures(
+    schema: &[(&str, FixtureColumn)],
+    theme: Theme,
+    seed: u64,
+    rows: usize,
+) -> String {
+    let mut csv = String::new();
+    let header = schema.iter().map(|(name, _)| name.to_string());
+    for record in [header.collect()]
+        .into_iter()
+        .chain(fixture_rows(schema, theme, seed, rows))
+    {
+        for (index, field) in record.iter().enumerate() {
+            if index > 0 {
+                csv.push(',');
+            }
+            push_csv_field(field, &mut csv);
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Values of `rows` fixture rows, one per `schema` column.
+fn fixture_rows(
+    schema: &[(&str, FixtureColumn)],
+    theme: Theme,
+    seed: u64,
+    rows: usize,
+) -> impl Iterator<Item = Vec<String>> {
+    let mut generator = NameGenerator::from_seed(seed).unique();
+    (1..=rows).map(move |id| {
+        let name = generator.name(theme);
+        schema
+            .iter()
+            .map(|(_, column)| match column {
+                FixtureColumn::Id => id.to_string(),
+                FixtureColumn::Name => name.clone(),
+                FixtureColumn::Theme => theme.name().to_string(),
+                FixtureColumn::Seed => seed.to_string(),
+            })
+            .collect()
+    })
+}
+
+fn push_csv_field(field: &str, csv: &mut String) {
+    if field.contains([',', '"', '\n', '\r']) {
+        csv.push('"');
+        csv.push_str(&field.replace('"', "\"\""));
+        csv.push('"');
+    } else {
+        csv.push_str(field);
+    }
+}
+
+/// Letter casing applied to every word of a rendered [`NamePair`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Case {
+    /// `Shiny Mango`
+    #[default]
+    Title,
+    /// `shiny mango`
+    Lower,
+    /// `SHINY MANGO`
+    Upper,
+    /// `shiny Mango`: like [`Case::Title`], except the very first word stays lowercase.
+    Camel,
+    /// `Shiny mango`: only the very first word is capitalized, as Spanish titles are written.
+    Sentence,
+}
+
+/// Options for [`NamePair::render`]: the casing plus the separator placed between words.
+///
+/// Multi-word nouns (`black cod`, `space-time`) are split into words, so the separator is used
+/// consistently across the whole name.
+///
+/// An optional length cap (`max_len`, in bytes, which also bounds the character count) keeps
+/// names within limits such as the 63 characters most cloud resource names allow; `overflow`
+/// picks how an over-long name is shortened.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Format<'a> {
+    pub case: Case,
+    pub separator: &'a str,
+    pub max_len: Option<usize>,
+    pub overflow: Overflow,
+    /// Transliterate accented Latin letters to ASCII and drop anything else that is not ASCII.
+    pub ascii: bool,
+    /// Word placed between the adjective and the noun, e.g. `of` or `&`.
+    pub joiner: Option<&'a str>,
+    pub order: WordOrder,
+}
+
+impl<'a> Format<'a> {
+    /// Create a format from a casing and a word separator.
+    pub const fn new(case: Case, separator: &'a str) -> Self {
+        Self {
+            case,
+            separator,
+            max_len: None,
+            overflow: Overflow::TruncateNoun,
+            ascii: false,
+            joiner: None,
+            order: WordOrder::AdjectiveFirst,
+        }
+    }
+
+    /// Render the noun before the adjective (see [`WordOrder`]).
+    pub const fn with_order(mut self, order: WordOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Put `joiner` between the adjective and the noun: `Fig & Falcon`, `Zesty of Quasar`.
+    ///
+    /// In Title Case the joiner is kept exactly as given, so small words such as `of` stay
+    /// lowercase; the other cases apply to it like to any word.
+    pub const fn with_joiner(mut self, joiner: &'a str) -> Self {
+        self.joiner = Some(joiner);
+        self
+    }
+
+    /// Guarantee pure-ASCII output: `Crème Brûlée` renders as `Creme Brulee`, and characters
+    /// without a Latin transliteration (including combining marks) are dropped.
+    pub const fn ascii_only(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    /// The separator as it appears in rendered names, folded like the words when ASCII-only.
+    fn written_separator(&self) -> Cow<'a, str> {
+        if !self.ascii || self.separator.is_ascii() {
+            return Cow::Borrowed(self.separator);
+        }
+        let mut folded = String::new();
+        fmt::Write::write_str(&mut AsciiFold(&mut folded), self.separator)
+            .expect("writing to a String cannot fail");
+        Cow::Owned(folded)
+    }
+
+    /// Cap the rendered name at `limit` bytes, shortening it with `overflow` when needed.
+    pub const fn with_max_len(mut self, limit: usize, overflow: Overflow) -> Self {
+        self.max_len = Some(limit);
+        self.overflow = overflow;
+        self
+    }
+}
+
+/// Which word of a pair comes first.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WordOrder {
+    /// `Zesty Quasar`.
+    #[default]
+    AdjectiveFirst,
+    /// `Quasar Zesty`, for call signs and lists sorted by noun.
+    NounFirst,
+    /// `Quasar, Zesty`, like an index entry; meant for Title, Lower or Upper case.
+    Inverted,
+}
+
+/// How [`Format::max_len`] shortens a name that is too long.
+///
+/// Every strategy falls back to cutting the end of the name, so the limit always holds.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Overflow {
+    /// Cut the end of the noun: `Shiny Watermel`.
+    #[default]
+    TruncateNoun,
+    /// Drop the adjective and keep the noun: `Watermelon`.
+    DropAdjective,
+    /// Shorten the adjective to its initial: `S Watermelon`.
+    Abbreviate,
+}
+
+impl Default for Format<'_> {
+    fn default() -> Self {
+        Self::new(Case::Title, " ")
+    }
+}
+
+/// Error returned by [`NamePair::format`] for malformed templates.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TemplateError {
+    /// A `{` without a matching `}`.
+    UnclosedPlaceholder,
+    /// A `}` that does not close a placeholder (use `}}` for a literal brace).
+    UnmatchedBrace,
+    /// A placeholder or style that the template engine does not know.
+    UnknownPlaceholder(String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedPlaceholder => f.write_str("template has an unclosed `{` placeholder"),
+            Self::UnmatchedBrace => {
+                f.write_str("template has an unmatched `}` (use `}}` for a literal brace)")
+            }
+            Self::UnknownPlaceholder(name) => {
+                write!(f, "unknown template placeholder `{{{name}}}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/// Quality warning reported by [`lint`] for a generated pair.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LintWarning {
+    /// The DNS-style (`shiny-mango`) form is longer than the 63 characters a DNS label allows.
+    TooLongForDns { len: usize },
+    /// The noun has several words (`black cod`), which some formats split or join awkwardly.
+    MultiWordNoun,
+    /// The adjective ends with the letter the noun starts with (`Zesty Yam`), which blurs the
+    /// join when the words are run together.
+    DoubleLetterAtJoin(char),
+    /// Lowercased and run together, a pair from a custom [`Vocabulary`] splits into a built-in
+    /// pair (`sunn` + `yapple` reads as `sunny apple`). Built-in pairs never clash with each
+    /// other, so they are not checked.
+    AmbiguousWhenLowercased {
+        adjective: &'static str,
+        noun: &'static str,
+    },
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLongForDns { len } => {
+                write!(f, "{len} characters is too long for a DNS label (max 63)")
+            }
+            Self::MultiWordNoun => f.write_str("the noun has more than one word"),
+            Self::DoubleLetterAtJoin(letter) => {
+                write!(f, "`{letter}` is doubled where the words meet")
+            }
+            Self::AmbiguousWhenLowercased { adjective, noun } => {
+                write!(f, "reads the same as `{adjective} {noun}` when lowercased")
+            }
+        }
+    }
+}
+
+/// Check a pair for traits that make it awkward as a name, so candidates can be flagged before
+/// anyone commits to one. An empty result means nothing was found.
+pub fn lint(pair: &NamePair) -> Vec<LintWarning> {
+    const MAX_DNS_LABEL_LEN: usize = 63;
+
+    let mut warnings = Vec::new();
+    let len = pair.render(Format::new(Case::Lower, "-")).chars().count();
+    if len > MAX_DNS_LABEL_LEN {
+        warnings.push(LintWarning::TooLongForDns { len });
+    }
+    if pair.noun.contains(is_word_break) {
+        warnings.push(LintWarning::MultiWordNoun);
+    }
+    let last = pair
+        .adjective
+        .chars()
+        .last()
+        .map(|c| c.to_ascii_lowercase());
+    let first = pair.noun.chars().next().map(|c| c.to_ascii_lowercase());
+    if let (Some(last), Some(first)) = (last, first)
+        && last == first
+        && last.is_alphabetic()
+    {
+        warnings.push(LintWarning::DoubleLetterAtJoin(last));
+    }
+
+    let built_in = THEMES.iter().any(|(_, list)| {
+        list.adjectives.contains(&pair.adjective) && list.nouns.contains(&pair.noun)
+    });
+    if built_in {
+        return warnings;
+    }
+    let compact = |word: &str| -> String {
+        word.chars()
+            .filter(|c| !is_word_break(*c))
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    let joined = compact(pair.adjective) + &compact(pair.noun);
+    for adjective in ADJECTIVES {
+        if *adjective == pair.adjective || !joined.starts_with(adjective) {
+            continue;
+        }
+        let rest = &joined[adjective.len()..];
+        let noun = THEMES
+            .iter()
+            .flat_map(|(_, list)| list.nouns.iter())
+            .find(|noun| compact(noun) == rest);
+        if let Some(noun) = noun {
+            warnings.push(LintWarning::AmbiguousWhenLowercased { adjective, noun });
+            break;
+        }
+    }
+    warnings
+}
+
+/// Name plus mascot from [`NameGenerator::named_entity`] (e.g. `Shiny Mango` with a `rocket`).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct NamedEntity {
+    /// Name rendered like the `*_name` helpers render it.
+    pub name: String,
+    /// Noun from the mascot theme, fixed by the name's words.
+    pub mascot: &'static str,
+}
+
+/// Robot/mecha designation made of a series code and a nickname (e.g. `XR-7 'Buttery Falcon'`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Designation {
+    /// Two uppercase series letters (`XR`).
+    pub series: [char; 2],
+    /// Model number within the series (`7`), between 1 and 999.
+    pub number: u16,
+    /// Friendly nickname drawn from the sci-fi list.
+    pub nickname: NamePair,
+}
+
+impl Designation {
+    /// Render only the alphanumeric code (e.g. `XR-7`).
+    pub fn code(&self) -> String {
+        format!("{}{}-{}", self.series[0], self.series[1], self.number)
+    }
+}
+
+impl fmt::Display for Designation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} '{}'", self.code(), self.nickname)
+    }
+}
+
+fn random_title(list: &WordLists) -> String {
+    random_pair(list).title_case()
+}
+
+fn random_pair(list: &WordLists) -> NamePair {
+    GLOBAL_RNG.with(|rng| select_pair(list, &mut rng.borrow_mut()))
+}
+
+fn select_pair(words: &WordLists, rng: &mut TinyRng) -> NamePair {
+    let adjective = words.adjectives[rng.index(words.adjectives.len())];
+    let noun = words.nouns[rng.index(words.nouns.len())];
+    NamePair { adjective, noun }
+}
+
+/// Pick a pair from `words` by splitting `hash` into an adjective and a noun index.
+fn hashed_pair(words: &WordLists, hash: u64) -> NamePair {
+    let adjectives = words.adjectives.len() as u64;
+    let adjective = words.adjectives[(hash % adjectives) as usize];
+    let noun = words.nouns[(hash / adjectives % words.nouns.len() as u64) as usize];
+    NamePair { adjective, noun }
+}
+
+/// Filters that narrow a theme's words, so a [`Pool`] is built once per configuration.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+struct Filters {
+    excluded_allergens: u16,
+    cuisines: u16,
+    categories: u16,
+    adjective_categories: u16,
+    max_tech_level: Option<TechLevel>,
+}
+
+/// Which [`Pool`] a draw uses: the theme, the locale its lists come from and the filters.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct PoolKey {
+    theme: Theme,
+    locale: Locale,
+    filters: Filters,
+}
+
+/// Pairs one draw chooses from: the adjectives and nouns of a word list that the active filters
+/// keep, in list order.
+#[derive(Clone, Debug)]
+struct Pool {
+    adjectives: Vec<&'static str>,
+    nouns: Vec<&'static str>,
+    adjective_set: HashSet<&'static str>,
+    noun_set: HashSet<&'static str>,
+    /// Pairs that are neither blocked nor ruled out by the provenance tag, counted the first time
+    /// they matter and kept up to date by [`NameGenerator::block`].
+    free: Option<usize>,
+}
+
+impl Pool {
+    /// The words of `theme` that `filters` keep.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the filters exclude every adjective or every noun.
+    fn new(theme: Theme, words: &WordLists, filters: Filters) -> Pool {
+        let adjectives: Vec<&'static str> = words
+            .adjectives
+            .iter()
+            .copied()
+            .filter(|adjective| in_adjective_categories(filters.adjective_categories, adjective))
+            .collect();
+        let nouns: Vec<&'static str> = words
+            .nouns
+            .iter()
+            .copied()
+            .filter(|noun| {
+                let allergen_free = food_allergens(noun)
+                    .iter()
+                    .all(|allergen| filters.excluded_allergens & allergen.bit() == 0);
+                let in_cuisine = filters.cuisines == 0
+                    || food_cuisines(noun)
+                        .iter()
+                        .any(|cuisine| filters.cuisines & cuisine.bit() != 0);
+                let in_tech_level = filters
+                    .max_tech_level
+                    .is_none_or(|max| scifi_tech_level(noun) <= max);
+                allergen_free
+                    && in_cuisine
+                    && in_tech_level
+                    && in_categories(filters.categories, noun)
+            })
+            .collect();
+        assert!(
+            !adjectives.is_empty() && !nouns.is_empty(),
+            "{} filters exclude every {} adjective or noun",
+            theme.name(),
+            theme.name()
+        );
+        Pool {
+            adjective_set: adjectives.iter().copied().collect(),
+            noun_set: nouns.iter().copied().collect(),
+            adjectives,
+            nouns,
+            free: None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.adjectives.len() * self.nouns.len()
+    }
+
+    fn contains(&self, pair: &NamePair) -> bool {
+        self.adjective_set.contains(pair.adjective) && self.noun_set.contains(pair.noun)
+    }
+
+    /// The `index`th pair, adjective-major.
+    fn pair(&self, index: usize) -> NamePair {
+        NamePair {
+            adjective: self.adjectives[index / self.nouns.len()],
+            noun: self.nouns[index % self.nouns.len()],
+        }
+    }
+
+    /// A random pair, drawn like [`select_pair`].
+    fn draw(&self, rng: &mut TinyRng) -> NamePair {
+        let adjective = self.adjectives[rng.index(self.adjectives.len())];
+        let noun = self.nouns[rng.index(self.nouns.len())];
+        NamePair { adjective, noun }
+    }
+}
+
+/// Whether `pair` carries the generator's `provenance` tag (always, without one).
+fn is_tagged(provenance: Option<(u16, u32)>, pair: &NamePair) -> bool {
+    provenance.is_none_or(|(tag, bits)| pair.provenance(bits) == tag)
+}
+
+/// A word other than `word` from the first of `lists` containing it, or `word` if none does.
+fn replace_word(
+    word: &'static str,
+    mut lists: impl Iterator<Item = &'static [&'static str]>,
+    rng: &mut TinyRng,
+) -> &'static str {
+    let Some(list) = lists.find(|list| list.contains(&word) && list.len() > 1) else {
+        return word;
+    };
+    let others = list.iter().filter(|&&other| other != word);
+    others
+        .clone()
+        .nth(rng.index(others.count()))
+        .unwrap_or(&word)
+}
+
+fn select_designation(rng: &mut TinyRng) -> Designation {
+    let series = [
+        SERIES_LETTERS[rng.index(SERIES_LETTERS.len())],
+        SERIES_LETTERS[rng.index(SERIES_LETTERS.len())],
+    ];
+    let number = 1 + rng.index(999) as u16;
+    let nickname = select_pair(&SCIFI_WORDS, rng);
+    Designation {
+        series,
+        number,
+        nickname,
+    }
+}
+
+fn render_template(
+    pair: &NamePair,
+    template: &str,
+    rng: &mut TinyRng,
+    (year, month, day): (i64, u32, u32),
+) -> Result<String, TemplateError> {
+    let mut text = String::with_capacity(template.len() + pair.adjective.len() + pair.noun.len());
+    let mut chars = template.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' => {
+                let rest = chars.as_str();
+                if let Some(stripped) = rest.strip_prefix('{') {
+                    text.push('{');
+                    chars = stripped.chars();
+                    continue;
+                }
+                let end = rest.find('}').ok_or(TemplateError::UnclosedPlaceholder)?;
+                let placeholder = &rest[..end];
+                chars = rest[end + 1..].chars();
+
+                let (field, style) = match placeholder.split_once(':') {
+                    Some((field, style)) => (field, Some(style)),
+                    None => (placeholder, None),
+                };
+                let unknown = || TemplateError::UnknownPlaceholder(placeholder.to_string());
+                match field {
+                    "adjective" | "noun" | "name" => {
+                        let words: &[&str] = match field {
+                            "adjective" => &[pair.adjective],
+                            "noun" => &[pair.noun],
+                            _ => &[pair.adjective, pair.noun],
+                        };
+                        let (case, separator) = match style.unwrap_or("lower") {
+                            "lower" => (Case::Lower, " "),
+                            "upper" => (Case::Upper, " "),
+                            "title" => (Case::Title, " "),
+                            "snake" => (Case::Lower, "_"),
+                            "kebab" => (Case::Lower, "-"),
+                            "pascal" => (Case::Title, ""),
+                            "camel" => (Case::Camel, ""),
+                            _ => return Err(unknown()),
+                        };
+                        write_words(words, Format::new(case, separator), &mut text)
+                            .expect("writing to a String cannot fail");
+                    }
+                    _ if style.is_some() => return Err(unknown()),
+                    "date" => text.push_str(&format!("{year:04}-{month:02}-{day:02}")),
+                    "year" => text.push_str(&format!("{year:04}")),
+                    "month" => text.push_str(&format!("{month:02}")),
+                    "day" => text.push_str(&format!("{day:02}")),
+                    _ => {
+                        let digits = field
+                            .strip_prefix("rand")
+                            .and_then(|n| n.parse::<usize>().ok())
+                            .filter(|n| (1..=32).contains(n))
+                            .ok_or_else(unknown)?;
+                        for _ in 0..digits {
+                            text.push(char::from(b'0' + rng.index(10) as u8));
+                        }
+                    }
+                }
+            }
+            '}' => {
+                let rest = chars.as_str();
+                let stripped = rest
+                    .strip_prefix('}')
+                    .ok_or(TemplateError::UnmatchedBrace)?;
+                text.push('}');
+                chars = stripped.chars();
+            }
+            _ => text.push(ch),
+        }
+    }
+    Ok(text)
+}
+
+/// Current UTC date as `(year, month, day)`.
+fn today_utc() -> (i64, u32, u32) {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    civil_from_days((secs / 86_400) as i64)
+}
+
+/// Convert days since the Unix epoch into a proleptic Gregorian `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn is_word_break(ch: char) -> bool {
+    ch == '-' || ch == '_' || ch == ' '
+}
+
+/// Cut `text` to at most `limit` bytes on a character boundary, without a dangling separator.
+fn truncate_name(mut text: String, limit: usize, separator: &str) -> String {
+    if text.len() > limit {
+        let mut end = limit;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+    }
+    if !separator.is_empty() {
+        while text.ends_with(separator) {
+            text.truncate(text.len() - separator.len());
+        }
+    }
+    text
+}
+
+fn render_words(words: &[&str], format: Format<'_>) -> String {
+    let mut text = String::with_capacity(
+        words
+            .iter()
+            .map(|word| word.len() + format.separator.len())
+            .sum(),
+    );
+    write_words(words, format, &mut text).expect("writing to a String cannot fail");
+    text
+}
+
+/// [`fmt::Write`] sink that only counts bytes.
+struct LenCounter(usize);
+
+impl fmt::Write for LenCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Write `words` with the requested casing, one grapheme cluster at a time.
+///
+/// Only the base character of a cluster is case-mapped; combining marks that follow it are copied
+/// unchanged, so decomposed accents (`e\u{301}`) stay attached to their letter and a leading mark
+/// does not swallow the capital of the word it precedes.
+fn write_words(words: &[&str], format: Format<'_>, out: &mut impl fmt::Write) -> fmt::Result {
+    if format.ascii {
+        return write_cased_words(words, format, &mut AsciiFold(out));
+    }
+    write_cased_words(words, format, out)
+}
+
+fn write_cased_words(words: &[&str], format: Format<'_>, out: &mut impl fmt::Write) -> fmt::Result {
+    let mut seen_letter = false;
+    for (index, word) in words.iter().enumerate() {
+        if index > 0 {
+            out.write_str(format.separator)?;
+        }
+        let mut start_of_word = true;
+        for cluster in grapheme_clusters(word) {
+            let mut chars = cluster.chars();
+            let Some(base) = chars.next() else {
+                continue;
+            };
+            if is_word_break(base) {
+                out.write_str(format.separator)?;
+                start_of_word = true;
+                continue;
+            }
+            if is_cluster_extender(base) {
+                out.write_str(cluster)?;
+                continue;
+            }
+            let upper = match format.case {
+                Case::Title => start_of_word,
+                Case::Camel => start_of_word && seen_letter,
+                Case::Sentence => !seen_letter,
+                Case::Lower => false,
+                Case::Upper => true,
+            };
+            if upper {
+                for c in base.to_uppercase() {
+                    out.write_char(c)?;
+                }
+            } else {
+                for c in base.to_lowercase() {
+                    out.write_char(c)?;
+                }
+            }
+            out.write_str(chars.as_str())?;
+            start_of_word = false;
+            seen_letter = true;
+        }
+    }
+    Ok(())
+}
+
+/// [`fmt::Write`] adapter that transliterates everything written through it to ASCII.
+struct AsciiFold<'a, W>(&'a mut W);
+
+impl<W: fmt::Write> fmt::Write for AsciiFold<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for ch in s.chars() {
+            if ch.is_ascii() {
+                self.0.write_char(ch)?;
+            } else {
+                self.0.write_str(ascii_fold(ch))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// ASCII transliteration of Latin-1 and Latin Extended-A letters; empty for anything else.
+fn ascii_fold(ch: char) -> &'static str {
+    match ch {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' => "A",
+        'Æ' => "AE",
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => "C",
+        'Ð' | 'Ď' | 'Đ' => "D",
+        'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => "E",
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => "G",
+        'Ĥ' | 'Ħ' => "H",
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => "I",
+        'Ĳ' => "IJ",
+        'Ĵ' => "J",
+        'Ķ' => "K",
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => "L",
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' | 'Ŋ' => "N",
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => "O",
+        'Œ' => "OE",
+        'Ŕ' | 'Ŗ' | 'Ř' => "R",
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' => "S",
+        'Ţ' | 'Ť' | 'Ŧ' => "T",
+        'Þ' => "TH",
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => "U",
+        'Ŵ' => "W",
+        'Ý' | 'Ŷ' | 'Ÿ' => "Y",
+        'Ź' | 'Ż' | 'Ž' => "Z",
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ð' | 'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĳ' => "ij",
+        'ĵ' => "j",
+        'ķ' | 'ĸ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' | 'ŉ' | 'ŋ' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ſ' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => "",
+    }
+}
+
+/// Split `word` into grapheme clusters: a base character followed by any combining marks,
+/// variation selectors or zero-width-joined characters.
+///
+/// This covers every cluster shape that matters for casing without pulling in the full Unicode
+/// segmentation tables.
+fn grapheme_clusters(word: &str) -> impl Iterator<Item = &str> {
+    let mut rest = word;
+    core::iter::from_fn(move || {
+        let mut chars = rest.char_indices();
+        let (_, first) = chars.next()?;
+        let mut end = first.len_utf8();
+        let mut joined = first == ZERO_WIDTH_JOINER;
+        for (index, ch) in chars {
+            if !joined && !is_cluster_extender(ch) {
+                break;
+            }
+            joined = ch == ZERO_WIDTH_JOINER;
+            end = index + ch.len_utf8();
+        }
+        let (cluster, tail) = rest.split_at(end);
+        rest = tail;
+        Some(cluster)
+    })
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+fn is_cluster_extender(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{0300}'..='\u{036F}'
+            | '\u{0483}'..='\u{0489}'
+            | '\u{0591}'..='\u{05BD}'
+            | '\u{0610}'..='\u{061A}'
+            | '\u{064B}'..='\u{065F}'
+            | '\u{0900}'..='\u{0903}'
+            | '\u{093A}'..='\u{094F}'
+            | '\u{0E31}'
+            | '\u{0E34}'..='\u{0E3A}'
+            | '\u{0E47}'..='\u{0E4E}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | ZERO_WIDTH_JOINER
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{3099}'..='\u{309A}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+/// FNV-1a over `bytes`, finished with the SplitMix64 mixer so nearby keys spread evenly.
+fn stable_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    mix64(hash)
+}
+
+/// SplitMix64 finalizer.
+fn mix64(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+#[derive(Clone, Copy)]
+struct TinyRng {
+    state: u64,
+    version: RngVersion,
+}
+
+impl TinyRng {
+    /// Seed from the clock and a counter, hashed with a [`RandomState`], whose keys std draws
+    /// from the operating system's random source, so processes started in the same nanosecond
+    /// still get different seeds.
+    fn seed_from_entropy(version: RngVersion) -> Self {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        let extra = ENTROPY_COUNTER.fetch_add(0x9E37, Ordering::Relaxed);
+        let os = RandomState::new().hash_one((time, extra));
+        Self::seeded(time ^ extra ^ extra.rotate_left(32) ^ os, version)
+    }
+
+    fn from_seed(seed: u64) -> Self {
+        Self::seeded(seed, RngVersion::V1)
+    }
+
+    fn seeded(seed: u64, version: RngVersion) -> Self {
+        match version {
+            RngVersion::V1 => Self::restored(seed, version),
+            // SplitMix64: step by the golden gamma, then mix.
+            RngVersion::V2 => {
+                Self::restored(mix64(seed.wrapping_add(0x9e37_79b9_7f4a_7c15)), version)
+            }
+        }
+    }
+
+    /// Continue from a `state` taken from a generator of `version`.
+    fn restored(state: u64, version: RngVersion) -> Self {
+        let state = match version {
+            // Xorshift must not start from zero; every other state is reachable.
+            RngVersion::V1 if state == 0 => 0x4d595df4d0f33173,
+            _ => state,
+        };
+        Self { state, version }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self.version {
+            RngVersion::V1 => {
+                self.state = xorshift(self.state);
+                self.state.wrapping_mul(0x2545F4914F6CDD1D)
+            }
+            RngVersion::V2 => {
+                // PCG RXS M XS 64/64: output a permutation of the state before an LCG step.
+                let old = self.state;
+                self.state = old.wrapping_mul(PCG_MULTIPLIER).wrapping_add(PCG_INCREMENT);
+                let word = ((old >> ((old >> 59) + 5)) ^ old).wrapping_mul(0xaef1_7502_108e_f2d9);
+                (word >> 43) ^ word
+            }
+        }
+    }
+
+    /// Advance the state by `draws` steps in O(log `draws`) time.
+    fn jump(&mut self, draws: u64) {
+        match self.version {
+            RngVersion::V1 => self.jump_xorshift(draws),
+            RngVersion::V2 => self.jump_pcg(draws),
+        }
+    }
+
+    /// Xorshift is linear over GF(2), so a step is a 64x64 bit matrix (stored as the images of
+    /// the 64 unit vectors) and `draws` steps are that matrix raised to `draws`.
+    fn jump_xorshift(&mut self, mut draws: u64) {
+        let apply = |matrix: &[u64; 64], x: u64| {
+            (0..64)
+                .filter(|bit| x >> bit & 1 == 1)
+                .fold(0, |image, bit| image ^ matrix[bit])
+        };
+        let mut matrix: [u64; 64] = core::array::from_fn(|bit| xorshift(1 << bit));
+        while draws > 0 {
+            if draws & 1 == 1 {
+                self.state = apply(&matrix, self.state);
+            }
+            matrix = matrix.map(|column| apply(&matrix, column));
+            draws >>= 1;
+        }
+    }
+
+    /// `draws` LCG steps compose into one affine step `state * multiplier + increment`, built by
+    /// squaring (Brown, "Random Number Generation with Arbitrary Strides").
+    fn jump_pcg(&mut self, mut draws: u64) {
+        let (mut multiplier, mut increment) = (1u64, 0u64);
+        let (mut step_multiplier, mut step_increment) = (PCG_MULTIPLIER, PCG_INCREMENT);
+        while draws > 0 {
+            if draws & 1 == 1 {
+                multiplier = multiplier.wrapping_mul(step_multiplier);
+                increment = increment
+                    .wrapping_mul(step_multiplier)
+                    .wrapping_add(step_increment);
+            }
+            step_increment = step_multiplier.wrapping_add(1).wrapping_mul(step_increment);
+            step_multiplier = step_multiplier.wrapping_mul(step_multiplier);
+            draws >>= 1;
+        }
+        self.state = self.state.wrapping_mul(multiplier).wrapping_add(increment);
+    }
+
+    fn index(&mut self, upper: usize) -> usize {
+        let bound = upper as u64;
+        if bound == 0 {
+            return 0;
+        }
+        (self.next_u64() % bound) as usize
+    }
+}
+
+/// Multiplier and increment of the 64-bit LCG under [`RngVersion::V2`] (Knuth's MMIX constants).
+const PCG_MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+const PCG_INCREMENT: u64 = 1_442_695_040_888_963_407;
+
+/// One xorshift64 step; never maps a non-zero state to zero.
+fn xorshift(mut x: u64) -> u64 {
+    x ^= x >> 12;
+    x ^= x << 25;
+    x ^= x >> 27;
+    x
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct WordLists {
+    adjectives: &'static [&'static str],
+    nouns: &'static [&'static str],
+}
+
+/// Series letters for robot designations; `I` and `O` are skipped so codes are not misread as digits.
+const SERIES_LETTERS: &[char] = &[
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J', 'K', 'L', 'M', 'N', 'P', 'Q', 'R', 'S', 'T', 'U',
+    'V', 'W', 'X', 'Y', 'Z',
+];
+
+/// Every built-in theme with the name used in exports.
+const THEMES: &[(&str, &WordLists)] = &[
+    ("food", &FOOD_WORDS),
+    ("scifi", &SCIFI_WORDS),
+    ("mineral", &MINERAL_WORDS),
+    ("mythology", &MYTHOLOGY_WORDS),
+    ("atompunk", &ATOMPUNK_WORDS),
+    ("weather", &WEATHER_WORDS),
+    ("music", &MUSIC_WORDS),
+    ("occupation", &OCCUPATION_WORDS),
+    ("fantasy", &FANTASY_WORDS),
+    ("animal", &ANIMAL_WORDS),
+    ("cyberpunk", &CYBERPUNK_WORDS),
+    ("celestial", &CELESTIAL_WORDS),
+    ("ocean", &OCEAN_WORDS),
+    ("element", &ELEMENT_WORDS),
+    ("arcade", &ARCADE_WORDS),
+    ("herb", &HERB_WORDS),
+    ("dessert", &DESSERT_WORDS),
+    ("drink", &DRINK_WORDS),
+    ("hybrid", &HYBRID_WORDS),
+];
+
+const ADJECTIVES: &[&str] = &[
+    "acidic",
+    "aged",
+    "agile",
+    "agreeable",
+    "airy",
+    "amber",
+    "ancient",
+    "angry",
+    "animated",
+    "anxious",
+    "aqua",
+    "aquamarine",
+    "arctic",
+    "aromatic",
+    "atomic",
+    "autumn",
+    "azure",
+    "balanced",
+    "balmy",
+    "bashful",
+    "beige",
+    "black",
+    "blazing",
+    "blissful",
+    "blue",
+    "bold",
+    "bouncy",
+    "breezy",
+    "bright",
+    "brilliant",
+    "brisk",
+    "brittle",
+    "bronze",
+    "brown",
+    "bubbling",
+    "bubbly",
+    "buoyant",
+    "buttery",
+    "buzzy",
+    "calm",
+    "candid",
+    "caramel",
+    "celestial",
+    "cheerful",
+    "cheery",
+    "chewy",
+    "chilly",
     "chrome",
     "citrus",
     "citrusy",
@@ -494,7 +6238,60 @@ const ADJECTIVES: &[&str] = &[
     "zippy",
 ];
 
+/// Sci-fi modifiers for the hybrid theme (`Quantum Quinoa`).
+const SCIFI_MODIFIERS: &[&str] = &[
+    "antimatter",
+    "astral",
+    "atomic",
+    "bionic",
+    "chrono",
+    "cosmic",
+    "cryo",
+    "cyber",
+    "electro",
+    "galactic",
+    "gravity",
+    "holo",
+    "hyper",
+    "interstellar",
+    "ion",
+    "laser",
+    "lunar",
+    "magnetic",
+    "nano",
+    "neutron",
+    "nuclear",
+    "orbital",
+    "photon",
+    "plasma",
+    "positronic",
+    "psionic",
+    "pulsar",
+    "quantum",
+    "radioactive",
+    "robotic",
+    "rocket",
+    "solar",
+    "sonic",
+    "space",
+    "stellar",
+    "subspace",
+    "tachyon",
+    "temporal",
+    "turbo",
+    "warp",
+    "xeno",
+    "zero-g",
+];
+
+/// Sci-fi modifiers paired with the food nouns.
+const HYBRID_WORDS: WordLists = WordLists {
+    adjectives: SCIFI_MODIFIERS,
+    nouns: FOOD_WORDS.nouns,
+};
+
 const FOOD_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
     nouns: &[
         "acai",
         "almond",
@@ -737,6 +6534,7 @@ const FOOD_WORDS: WordLists = WordLists {
 };
 
 const SCIFI_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
     nouns: &[
         "ablative plating",
         "ai nexus",
@@ -868,11 +6666,14 @@ const SCIFI_WORDS: WordLists = WordLists {
         "plasma",
         "plasma battery",
         "portal",
+        #[cfg(not(feature = "safe"))]
         "deathstar",
         "star cruiser",
         "mind control",
+        #[cfg(not(feature = "safe"))]
         "cyberpunk",
         "robodog",
+        #[cfg(not(feature = "safe"))]
         "robocop",
         "positronic brain",
         "power conduit",
@@ -924,6 +6725,7 @@ const SCIFI_WORDS: WordLists = WordLists {
         "star chart",
         "star cluster",
         "star forge",
+        #[cfg(not(feature = "safe"))]
         "star gate",
         "star map",
         "starbase",
@@ -946,6 +6748,7 @@ const SCIFI_WORDS: WordLists = WordLists {
         "thruster",
         "transponder",
         "transporter",
+        #[cfg(not(feature = "safe"))]
         "tricorder",
         "triple star",
         "ufo",
@@ -1017,103 +6820,3186 @@ const SCIFI_WORDS: WordLists = WordLists {
         "titanium alloy",
         "stainless steel",
         "carbon steel",
+        #[cfg(not(feature = "safe"))]
+        "adamantium",
+        #[cfg(not(feature = "safe"))]
+        "vibranium",
+        #[cfg(not(feature = "safe"))]
+        "mithril",
+        #[cfg(not(feature = "safe"))]
+        "beskar",
+        "unobtanium",
+        "durasteel",
+        #[cfg(not(feature = "safe"))]
+        "tritanium",
+        "dilithium",
+        "neutronium",
+        "orichalcum",
+        #[cfg(not(feature = "safe"))]
+        "valyrian steel",
+        "star metal",
+        #[cfg(not(feature = "safe"))]
+        "nth metal",
+        "plasteel",
+        "nanosteel",
+        #[cfg(not(feature = "safe"))]
+        "carbonite",
+        #[cfg(not(feature = "safe"))]
+        "kyber",
+        #[cfg(not(feature = "safe"))]
+        "energon",
+        "electrum",
+        "meteoric iron",
+        "graphene",
+        "graphite",
+        "carbon fiber",
+        "nanotube",
+        "nanofiber",
+        "aerogel",
+        #[cfg(not(feature = "safe"))]
+        "kevlar",
+        #[cfg(not(feature = "safe"))]
+        "mylar",
+        "polymer",
+        "bioplastic",
+        "transparent aluminum",
+        "fused quartz",
+        "obsidian",
+        "quartz",
+        "diamond",
+        "sapphire",
+        "emerald",
+        "ruby",
+        "amethyst",
+        "topaz",
+        "jade",
+        "onyx",
+        "opal",
+        "moonstone",
+        "sunstone",
+        #[cfg(not(feature = "safe"))]
+        "element zero",
+        "ceramic",
+        "glass",
+        "tempered glass",
+        "fiber optic",
+        "superconductor",
+        "superalloy",
+        "hyperalloy",
+        "memory metal",
+        "living metal",
+        "liquid metal",
+        "smart metal",
+        "bioalloy",
+        "nanoglass",
+        "quantum glass",
+        "helium-3",
+        "tritium",
+        "deuterium",
+        "ferrite",
+        "alloy",
+        "ingot",
+    ],
+};
+
+const MINERAL_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "agate",
+        "alabaster",
+        "alexandrite",
+        "amazonite",
+        "amber",
+        "andesite",
+        "apatite",
+        "aquamarine",
+        "aragonite",
+        "azurite",
+        "basalt",
+        "benitoite",
+        "beryl",
+        "bloodstone",
+        "calcite",
+        "carnelian",
+        "celestite",
+        "chalcedony",
+        "chrysoberyl",
+        "chrysocolla",
+        "chrysoprase",
+        "cinnabar",
+        "citrine",
+        "corundum",
+        "diorite",
+        "dolomite",
+        "feldspar",
+        "fluorite",
+        "gabbro",
+        "galena",
+        "garnet",
+        "geode",
+        "gneiss",
+        "granite",
+        "gypsum",
+        "heliodor",
+        "hematite",
+        "howlite",
+        "iolite",
+        "jasper",
+        "jet",
+        "kunzite",
+        "kyanite",
+        "labradorite",
+        "lapis lazuli",
+        "larimar",
+        "limestone",
+        "lodestone",
+        "magnetite",
+        "malachite",
+        "marble",
+        "mica",
+        "moldavite",
+        "morganite",
+        "nephrite",
+        "olivine",
+        "peridot",
+        "pumice",
+        "pyrite",
+        "quartzite",
+        "rhodonite",
+        "rose quartz",
+        "rutile",
+        "sandstone",
+        "sardonyx",
+        "schist",
+        "selenite",
+        "serpentine",
+        "shale",
+        "slate",
+        "smoky quartz",
+        "sodalite",
+        "spinel",
+        "sugilite",
+        "talc",
+        "tanzanite",
+        "tigers eye",
+        "tourmaline",
+        "turquoise",
+        "zoisite",
+    ],
+};
+
+const MYTHOLOGY_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "aegis",
+        "ambrosia",
+        "anubis",
+        "argonaut",
+        "asgard",
+        "atlantis",
+        "avalon",
+        "banshee",
+        "basilisk",
+        "behemoth",
+        "bifrost",
+        "centaur",
+        "cerberus",
+        "charybdis",
+        "chimera",
+        "colossus",
+        "cyclops",
+        "djinn",
+        "dragon",
+        "dryad",
+        "elysium",
+        "excalibur",
+        "fenrir",
+        "fury",
+        "garuda",
+        "golden fleece",
+        "golem",
+        "gorgon",
+        "griffin",
+        "harpy",
+        "hesperides",
+        "hippogriff",
+        "hydra",
+        "kelpie",
+        "kitsune",
+        "kraken",
+        "labyrinth",
+        "leviathan",
+        "manticore",
+        "medusa",
+        "minotaur",
+        "mjolnir",
+        "naga",
+        "nemean lion",
+        "nereid",
+        "nymph",
+        "odyssey",
+        "olympus",
+        "oracle",
+        "osiris",
+        "ouroboros",
+        "pandora",
+        "pegasus",
+        "phoenix",
+        "qilin",
+        "ragnarok",
+        "roc",
+        "satyr",
+        "scylla",
+        "selkie",
+        "simurgh",
+        "siren",
+        "sleipnir",
+        "sphinx",
+        "styx",
+        "sunbird",
+        "tengu",
+        "thunderbird",
+        "thunderbolt",
+        "titan",
+        "trident",
+        "troll",
+        "underworld",
+        "unicorn",
+        "valhalla",
+        "valkyrie",
+        "wendigo",
+        "wyvern",
+        "yeti",
+        "yggdrasil",
+    ],
+};
+
+const ATOMPUNK_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "aero car",
+        "atom smasher",
+        "atomic car",
+        "atomic clock",
+        "autogyro",
+        "automat",
+        "bubble helmet",
+        "chrome bumper",
+        "cloud city",
+        "console",
+        "control panel",
+        "death ray",
+        "dynamo",
+        "flying saucer",
+        "geiger counter",
+        "googie diner",
+        "gyrocopter",
+        "hover car",
+        "hyperloop",
+        "jet belt",
+        "jet car",
+        "mainframe",
+        "monorail",
+        "moon rocket",
+        "mushroom cloud",
+        "nixie tube",
+        "picture phone",
+        "punch card",
+        "radar dish",
+        "radiogram",
+        "ray gun",
+        "reel to reel",
+        "retro rocket",
+        "robot butler",
+        "rocket fin",
+        "rocket pack",
+        "servo",
+        "sky hook",
+        "slide rule",
+        "sonic oven",
+        "space helmet",
+        "space pistol",
+        "spark gap",
+        "starliner",
+        "switchboard",
+        "tail fin",
+        #[cfg(not(feature = "safe"))]
+        "telecaster",
+        "teleprinter",
+        "televisor",
+        "tesla coil",
+        "tin robot",
+        "transistor",
+        "tube radio",
+        "vacuum tube",
+        "visiphone",
+        "wonder ray",
+        "zap gun",
+        "zeppelin",
+    ],
+};
+
+const WEATHER_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "anticyclone",
+        "avalanche",
+        "barometer",
+        "blizzard",
+        "breeze",
+        "chinook",
+        "cirrus",
+        "cloudburst",
+        "cold front",
+        "cumulonimbus",
+        "cumulus",
+        "cyclone",
+        "derecho",
+        "dew",
+        "doldrums",
+        "downpour",
+        "drizzle",
+        "drought",
+        "dust devil",
+        "fog bank",
+        "frost",
+        "gale",
+        "graupel",
+        "gust",
+        "haboob",
+        "hail",
+        "haze",
+        "heatwave",
+        "hurricane",
+        "ice storm",
+        "jet stream",
+        "lightning",
+        "mist",
+        "mistral",
+        "monsoon",
+        "nimbus",
+        "petrichor",
+        "polar vortex",
+        "rainbow",
+        "rainfall",
+        "sandstorm",
+        "sirocco",
+        "sleet",
+        "snowdrift",
+        "snowfall",
+        "squall",
+        "stratus",
+        "sunbeam",
+        "sundog",
+        "sunshower",
+        "supercell",
+        "tempest",
+        "thunderclap",
+        "thunderhead",
+        "thunderstorm",
+        "tornado",
+        "trade wind",
+        "tsunami",
+        "twister",
+        "typhoon",
+        "updraft",
+        "waterspout",
+        "whirlwind",
+        "whiteout",
+        "williwaw",
+        "windchill",
+    ],
+};
+
+const MUSIC_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "adagio",
+        "allegro",
+        "anthem",
+        "aria",
+        "arpeggio",
+        "backbeat",
+        "ballad",
+        "bass drop",
+        "bassline",
+        "beat",
+        "bolero",
+        "bridge",
+        "cadence",
+        "cadenza",
+        "canon",
+        "cantata",
+        "chord",
+        "chorus",
+        "coda",
+        "concerto",
+        "crescendo",
+        "cymbal",
+        "downbeat",
+        "drum machine",
+        "drumroll",
+        "duet",
+        "echo",
+        "encore",
+        "etude",
+        "falsetto",
+        "fanfare",
+        "fermata",
+        "fugue",
+        "groove",
+        "harmonica",
+        "harmony",
+        "hook",
+        "interlude",
+        "jingle",
+        "kazoo",
+        "lullaby",
+        "lyric",
+        "madrigal",
+        "measure",
+        "melody",
+        "metronome",
+        "minuet",
+        "mixtape",
+        "motif",
+        "nocturne",
+        "octave",
+        "opus",
+        "oscillator",
+        "overture",
+        "prelude",
+        "refrain",
+        "reverb",
+        "rhapsody",
+        "rhythm",
+        "riff",
+        "rondo",
+        "sampler",
+        "scherzo",
+        "serenade",
+        "sonata",
+        "staccato",
+        "subwoofer",
+        "symphony",
+        "synth",
+        "tempo",
+        "theremin",
+        "timbre",
+        "tremolo",
+        "trill",
+        "tuning fork",
+        "turntable",
+        "ukulele",
+        "vibrato",
+        "vinyl",
+        "waltz",
+    ],
+};
+
+const OCCUPATION_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "alchemist",
+        "apothecary",
+        "architect",
+        "archivist",
+        "artisan",
+        "astronomer",
+        "baker",
+        "bard",
+        "beachcomber",
+        "beekeeper",
+        "blacksmith",
+        "bookbinder",
+        "botanist",
+        "brewer",
+        "carpenter",
+        "cartographer",
+        "chemist",
+        "chronicler",
+        "clockmaker",
+        "cobbler",
+        "codebreaker",
+        "cooper",
+        "courier",
+        "curator",
+        "diplomat",
+        "drover",
+        "engineer",
+        "falconer",
+        "farmer",
+        "ferryman",
+        "fisher",
+        "forager",
+        "gardener",
+        "geologist",
+        "glassblower",
+        "herbalist",
+        "historian",
+        "innkeeper",
+        "inventor",
+        "jeweler",
+        "librarian",
+        "lighthouse keeper",
+        "locksmith",
+        "luthier",
+        "machinist",
+        "mapmaker",
+        "mason",
+        "mechanic",
+        "merchant",
+        "miner",
+        "navigator",
+        "pilot",
+        "potter",
+        "quartermaster",
+        "scholar",
+        "scribe",
+        "sculptor",
+        "shepherd",
+        "shipwright",
+        "smith",
+        "stargazer",
+        "surveyor",
+        "tailor",
+        "tinker",
+        "translator",
+        "watchmaker",
+        "weaver",
+        "woodcarver",
+        "zoologist",
+    ],
+};
+
+const FANTASY_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "amulet",
+        "archmage",
+        "bastion",
+        "battleaxe",
+        #[cfg(not(feature = "safe"))]
+        "beholder",
+        "broadsword",
+        "castle",
+        "cauldron",
+        "chalice",
+        "crown",
+        "crystal ball",
+        "dragonscale",
+        "drake",
+        "druid",
+        "dungeon",
+        "dwarf",
+        "elf",
+        "elixir",
+        "enchantment",
+        "fairy",
+        "familiar",
+        "fortress",
+        "gauntlet",
+        "goblin",
+        "grimoire",
+        "halberd",
+        #[cfg(not(feature = "safe"))]
+        "hobbit",
+        "keep",
+        "knight",
+        "lich",
+        "longbow",
+        "mage",
+        "mana crystal",
+        "moat",
+        "necromancer",
+        "ogre",
+        "orb",
+        "orc",
+        "paladin",
+        "phylactery",
+        "pixie",
+        "portcullis",
+        "potion",
+        "quest",
+        "relic",
+        "rune",
+        "runestone",
+        "scepter",
+        "scroll",
+        "shield",
+        "sorcerer",
+        "spellbook",
+        "sprite",
+        "staff",
+        "sword",
+        "talisman",
+        "tavern",
+        "throne",
+        "tome",
+        "tower",
+        "treant",
+        "wand",
+        "warlock",
+        "wizard",
+        "wraith",
+        "wyrm",
+    ],
+};
+
+const ANIMAL_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "aardvark",
+        "albatross",
+        "alpaca",
+        "armadillo",
+        "axolotl",
+        "badger",
+        "beaver",
+        "bison",
+        "bobcat",
+        "buffalo",
+        "capybara",
+        "caribou",
+        "chameleon",
+        "cheetah",
+        "chinchilla",
+        "cougar",
+        "coyote",
+        "crane",
+        "dingo",
+        "dolphin",
+        "dormouse",
+        "eagle",
+        "egret",
+        "elk",
+        "ermine",
+        "ferret",
+        "finch",
+        "flamingo",
+        "fox",
+        "gazelle",
+        "gecko",
+        "gibbon",
+        "giraffe",
+        "gopher",
+        "hedgehog",
+        "heron",
+        "hippo",
+        "hummingbird",
+        "ibis",
+        "iguana",
+        "impala",
+        "jackal",
+        "jaguar",
+        "kangaroo",
+        "kestrel",
+        "kingfisher",
+        "koala",
+        "lemur",
+        "leopard",
+        "llama",
+        "lynx",
+        "macaw",
+        "manatee",
+        "marmot",
+        "meerkat",
+        "mink",
+        "mongoose",
+        "moose",
+        "narwhal",
+        "ocelot",
+        "okapi",
+        "orca",
+        "osprey",
+        "otter",
+        "owl",
+        "panda",
+        "pangolin",
+        "panther",
+        "parrot",
+        "pelican",
+        "penguin",
+        "platypus",
+        "porcupine",
+        "puffin",
+        "quail",
+        "quokka",
+        "rabbit",
+        "raccoon",
+        "raven",
+        "reindeer",
+        "robin",
+        "salamander",
+        "seal",
+        "sparrow",
+        "squirrel",
+        "stoat",
+        "swan",
+        "tapir",
+        "tiger",
+        "toucan",
+        "turtle",
+        "walrus",
+        "weasel",
+        "wolf",
+        "wolverine",
+        "wombat",
+        "yak",
+        "zebra",
+    ],
+};
+
+const CYBERPUNK_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "arcology",
+        "augment",
+        "backdoor",
+        "black ice",
+        #[cfg(not(feature = "safe"))]
+        "braindance",
+        "chipset",
+        "chrome deck",
+        "cipher",
+        "console cowboy",
+        "cyber eye",
+        "cyberdeck",
+        "cyberspace",
+        "darknet",
+        "data broker",
+        "data heist",
+        "datajack",
+        "datashard",
+        "drone swarm",
+        "exploit",
+        "fixer",
+        "flatline",
+        "ghost protocol",
+        "glitch",
+        "grid",
+        "hacker",
+        "holo ad",
+        "hoverbike",
+        "ice breaker",
+        "implant",
+        "katana",
+        "killswitch",
+        "megablock",
+        "megacity",
+        "megacorp",
+        "mirrorshades",
+        "mod",
+        "mono wire",
+        "nanite",
+        "neon alley",
+        "neon sign",
+        #[cfg(not(feature = "safe"))]
+        "netrunner",
+        "neural link",
+        "nightclub",
+        "noodle stall",
+        "optic",
+        "overlay",
+        "payload",
+        "proxy",
+        "rain slick",
+        "rooftop",
+        "rootkit",
+        "samurai",
+        "server farm",
+        "simstim",
+        "skyline",
+        "slum",
+        "smartgun",
+        "spinner",
+        "sprawl",
+        "street doc",
+        "subnet",
+        "synthwave",
+        "trench coat",
+        "uplink",
+        "virus",
+        "wetware",
+        "wirehead",
+        "zaibatsu",
+        "zero day",
+    ],
+};
+
+const CELESTIAL_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "accretion disk",
+        "aphelion",
+        "apogee",
+        "blazar",
+        "brown dwarf",
+        "celestial sphere",
+        "cepheid",
+        "corona",
+        "crescent",
+        "ecliptic",
+        "equinox",
+        "evening star",
+        "firmament",
+        "full moon",
+        "gibbous moon",
+        "half moon",
+        "light year",
+        "lunar eclipse",
+        "meteoroid",
+        "milky way",
+        "moon",
+        "morning star",
+        "neutron star",
+        "new moon",
+        "north star",
+        "oort cloud",
+        "penumbra",
+        "perigee",
+        "perihelion",
+        "planet",
+        "planetoid",
+        "redshift",
+        "star field",
+        "starburst",
+        "stardust",
+        "sun",
+        "sunspot",
+        "syzygy",
+        "trojan",
+        "umbra",
+    ],
+};
+
+const OCEAN_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "abyss",
+        "anemone",
+        "atoll",
+        "barnacle",
+        "bathysphere",
+        "bioluminescence",
+        "brine pool",
+        "coral reef",
+        "current",
+        "deep",
+        "diving bell",
+        "eelgrass",
+        "hydrothermal vent",
+        "jellyfish",
+        "kelp forest",
+        "krill",
+        "lagoon",
+        "manta ray",
+        "nautilus",
+        "pearl",
+        "plankton",
+        "riptide",
+        "sea cave",
+        "sea urchin",
+        "seafloor",
+        "seahorse",
+        "seamount",
+        "shipwreck",
+        "shoal",
+        "sonar",
+        "starfish",
+        "submarine",
+        "swell",
+        "tide pool",
+        "trench",
+        "undertow",
+        "whale",
+    ],
+};
+
+const ELEMENT_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "actinium",
+        "americium",
+        "antimony",
+        "astatine",
+        "berkelium",
+        "californium",
+        "cerium",
+        "curium",
+        "dysprosium",
+        "einsteinium",
+        "erbium",
+        "europium",
+        "fermium",
+        "francium",
+        "gadolinium",
+        "germanium",
+        "hafnium",
+        "holmium",
+        "indium",
+        "iridium",
+        "lanthanum",
+        "lutetium",
+        "molybdenum",
+        "neodymium",
+        "neptunium",
+        "niobium",
+        "nobelium",
+        "osmium",
+        "polonium",
+        "praseodymium",
+        "promethium",
+        "rhenium",
+        "rhodium",
+        "rubidium",
+        "ruthenium",
+        "samarium",
+        "scandium",
+        "selenium",
+        "tantalum",
+        "technetium",
+        "tellurium",
+        "terbium",
+        "thallium",
+        "thulium",
+        "vanadium",
+        "ytterbium",
+        "yttrium",
+        "zirconium",
+    ],
+};
+
+const ARCADE_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "arcade cabinet",
+        "bonus round",
+        "boss fight",
+        "cartridge",
+        "cheat code",
+        "checkpoint",
+        "coin slot",
+        "combo",
+        "continue screen",
+        "controller",
+        "cutscene",
+        "d-pad",
+        "extra life",
+        "final boss",
+        "game over",
+        "high score",
+        "insert coin",
+        "joystick",
+        "level up",
+        "light gun",
+        "loading screen",
+        "mascot",
+        "mini-game",
+        "pinball",
+        "pixel",
+        "platformer",
+        "power-up",
+        "respawn",
+        "save point",
+        "scanline",
+        "side-scroller",
+        "speedrun",
+        "start button",
+        "tilemap",
+        "token",
+        "trackball",
+        "warp zone",
+        "wavetable",
+    ],
+};
+
+const HERB_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "allspice",
+        "anise",
+        "bay leaf",
+        "black pepper",
+        "caraway",
+        "cardamom",
+        "cayenne",
+        "chervil",
+        "chili",
+        "chive",
+        "cinnamon",
+        "clove",
+        "coriander",
+        "cumin",
+        "curry leaf",
+        "dill",
+        "fenugreek",
+        "galangal",
+        "juniper",
+        "lavender",
+        "lemongrass",
+        "lovage",
+        "mace",
+        "marjoram",
+        "mint",
+        "mustard seed",
+        "oregano",
+        "paprika",
+        "parsley",
+        "peppercorn",
+        "rosemary",
+        "saffron",
+        "sage",
+        "sorrel",
+        "star anise",
+        "sumac",
+        "tarragon",
+        "thyme",
+        "turmeric",
+        "vanilla",
+        "wasabi",
+        "za'atar",
+    ],
+};
+
+const DESSERT_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "baklava",
+        "banana split",
+        "beignet",
+        "biscotti",
+        "blondie",
+        "bread pudding",
+        "cannoli",
+        "cheesecake",
+        "clafoutis",
+        "creme brulee",
+        "crumble",
+        "cupcake",
+        "custard",
+        "donut",
+        "eclair",
+        "flan",
+        "fudge",
+        "gelato",
+        "gingerbread",
+        "honey cake",
+        "key lime pie",
+        "lava cake",
+        "macaron",
+        "madeleine",
+        "marzipan",
+        "meringue",
+        "mousse",
+        "panna cotta",
+        "parfait",
+        "pavlova",
+        "profiterole",
+        "rice pudding",
+        "souffle",
+        "strudel",
+        "tiramisu",
+        "trifle",
+        "turnover",
+        "whoopie pie",
+    ],
+};
+
+const DRINK_WORDS: WordLists = WordLists {
+    adjectives: ADJECTIVES,
+    nouns: &[
+        "americano",
+        "bellini",
+        "bubble tea",
+        "cappuccino",
+        "chai",
+        "cider",
+        "cold brew",
+        "cortado",
+        "daiquiri",
+        "eggnog",
+        "espresso",
+        "gimlet",
+        "highball",
+        "horchata",
+        "hot chocolate",
+        "iced tea",
+        "kefir",
+        "kombucha",
+        "lassi",
+        "latte",
+        "lemonade",
+        "macchiato",
+        "manhattan",
+        "margarita",
+        "martini",
+        "matcha",
+        "mead",
+        "milkshake",
+        "mimosa",
+        "mint julep",
+        "mojito",
+        "mulled wine",
+        "negroni",
+        "old fashioned",
+        "pina colada",
+        "root beer",
+        "sake",
+        "sangria",
+        "smoothie",
+        "sour",
+        "spritz",
+        "tonic",
+        "whiskey sour",
+        "yerba mate",
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn title_case_formats_correctly() {
+        let pair = NamePair {
+            adjective: "shiny",
+            noun: "mango",
+        };
+        assert_eq!(pair.title_case(), "Shiny Mango");
+    }
+
+    #[test]
+    fn lowercase_and_uppercase_keep_spaces() {
+        let pair = NamePair {
+            adjective: "Shiny",
+            noun: "space-time",
+        };
+        assert_eq!(pair.lowercase(), "shiny space time");
+        assert_eq!(pair.uppercase(), "SHINY SPACE TIME");
+    }
+
+    #[test]
+    fn snake_case_splits_multi_word_nouns() {
+        let pair = NamePair {
+            adjective: "Shiny",
+            noun: "black cod",
+        };
+        assert_eq!(pair.snake_case(), "shiny_black_cod");
+
+        let pair = NamePair {
+            adjective: "brisk",
+            noun: "space-time",
+        };
+        assert_eq!(pair.snake_case(), "brisk_space_time");
+    }
+
+    #[test]
+    fn pascal_and_camel_case_join_words() {
+        let pair = NamePair {
+            adjective: "shiny",
+            noun: "mango",
+        };
+        assert_eq!(pair.pascal_case(), "ShinyMango");
+        assert_eq!(pair.camel_case(), "shinyMango");
+
+        let pair = NamePair {
+            adjective: "brisk",
+            noun: "space-time",
+        };
+        assert_eq!(pair.pascal_case(), "BriskSpaceTime");
+        assert_eq!(pair.camel_case(), "briskSpaceTime");
+    }
+
+    #[test]
+    fn casing_keeps_combining_marks_with_their_letter() {
+        let pair = NamePair {
+            adjective: "e\u{301}pice\u{301}",
+            noun: "e\u{301}clair",
+        };
+        assert_eq!(pair.title_case(), "E\u{301}pice\u{301} E\u{301}clair");
+        assert_eq!(pair.camel_case(), "e\u{301}pice\u{301}E\u{301}clair");
+        assert_eq!(pair.uppercase(), "E\u{301}PICE\u{301} E\u{301}CLAIR");
+
+        let pair = NamePair {
+            adjective: "\u{301}sunny",
+            noun: "\u{3b1}\u{345}pple",
+        };
+        assert_eq!(pair.title_case(), "\u{301}Sunny \u{391}\u{345}pple");
+        assert_eq!(pair.uppercase(), "\u{301}SUNNY \u{391}\u{345}PPLE");
+    }
+
+    #[test]
+    fn ascii_only_transliterates_or_drops() {
+        let pair = NamePair {
+            adjective: "crème",
+            noun: "bru\u{302}le\u{301}e straße",
+        };
+        assert!(!pair.is_ascii());
+        let ascii = Format::default().ascii_only();
+        assert_eq!(pair.render(ascii), "Creme Brulee Strasse");
+        assert_eq!(
+            pair.render(Format::new(Case::Upper, "_").ascii_only()),
+            "CREME_BRULEE_STRASSE"
+        );
+        assert_eq!(pair.identifier(), "creme_brulee_strasse");
+        assert_eq!(pair.dns_label(), "creme-brulee-strasse");
+
+        let pair = NamePair {
+            adjective: "sunny",
+            noun: "日本 mango",
+        };
+        assert!(pair.render(ascii).is_ascii());
+
+        let pair = NamePair {
+            adjective: "zesty",
+            noun: "quasar",
+        };
+        assert_eq!(pair.render(ascii.with_joiner("é")), "Zesty e Quasar");
+        assert_eq!(
+            pair.render(Format::new(Case::Camel, "").with_joiner("é").ascii_only()),
+            "zestyEQuasar"
+        );
+        assert_eq!(
+            pair.render(
+                Format::new(Case::Lower, "·")
+                    .ascii_only()
+                    .with_order(WordOrder::Inverted)
+            ),
+            "quasar,zesty"
+        );
+        let mut generator = NameGenerator::from_seed(1)
+            .with_format(Format::new(Case::Lower, "–").ascii_only())
+            .with_number_suffix(2)
+            .with_sequence(Sequence::Counter { start: 1, width: 2 });
+        let name = generator.food_name();
+        assert!(name.is_ascii(), "{name}");
+    }
+
+    #[test]
+    fn join_and_render_use_separator_between_every_word() {
+        let pair = NamePair {
+            adjective: "shiny",
+            noun: "black cod",
+        };
+        assert_eq!(pair.join("."), "Shiny.Black.Cod");
+        assert_eq!(
+            pair.render(Format::new(Case::Lower, "+")),
+            "shiny+black+cod"
+        );
+        assert_eq!(
+            pair.render(Format::new(Case::Upper, "-")),
+            "SHINY-BLACK-COD"
+        );
+    }
+
+    #[test]
+    fn combinations_exceed_minimums() {
+        assert!(ADJECTIVES.len() * FOOD_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * SCIFI_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * MINERAL_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * MYTHOLOGY_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * ATOMPUNK_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * WEATHER_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * MUSIC_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * OCCUPATION_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * FANTASY_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * ANIMAL_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * CYBERPUNK_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * CELESTIAL_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * OCEAN_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * ELEMENT_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * ARCADE_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * HERB_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * DESSERT_WORDS.nouns.len() >= 1000);
+        assert!(ADJECTIVES.len() * DRINK_WORDS.nouns.len() >= 1000);
+        assert!(SCIFI_MODIFIERS.len() * FOOD_WORDS.nouns.len() >= 1000);
+    }
+
+    #[test]
+    fn element_names_use_only_element_nouns() {
+        let mut generator = NameGenerator::from_seed(5);
+        for _ in 0..50 {
+            let pair = generator.element_words();
+            assert!(ELEMENT_WORDS.nouns.contains(&pair.noun), "{pair:?}");
+        }
+        let name = random_element_name().to_lowercase();
+        assert!(
+            ELEMENT_WORDS.nouns.iter().any(|noun| name.ends_with(noun)),
+            "{name}"
+        );
+        for (theme, list) in THEMES.iter().filter(|(theme, _)| *theme != "element") {
+            for noun in list.nouns {
+                assert!(
+                    !ELEMENT_WORDS.nouns.contains(noun),
+                    "{theme} also lists {noun}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn theme_names_use_only_their_own_words() {
+        type RandomName = fn() -> String;
+        let themes: [(RandomName, &WordLists); 19] = [
+            (random_food_name, &FOOD_WORDS),
+            (random_scifi_name, &SCIFI_WORDS),
+            (random_mineral_name, &MINERAL_WORDS),
+            (random_mythology_name, &MYTHOLOGY_WORDS),
+            (random_atompunk_name, &ATOMPUNK_WORDS),
+            (random_weather_name, &WEATHER_WORDS),
+            (random_music_name, &MUSIC_WORDS),
+            (random_occupation_name, &OCCUPATION_WORDS),
+            (random_fantasy_name, &FANTASY_WORDS),
+            (random_animal_name, &ANIMAL_WORDS),
+            (random_cyberpunk_name, &CYBERPUNK_WORDS),
+            (random_celestial_name, &CELESTIAL_WORDS),
+            (random_ocean_name, &OCEAN_WORDS),
+            (random_element_name, &ELEMENT_WORDS),
+            (random_arcade_name, &ARCADE_WORDS),
+            (random_herb_name, &HERB_WORDS),
+            (random_dessert_name, &DESSERT_WORDS),
+            (random_drink_name, &DRINK_WORDS),
+            (random_hybrid_name, &HYBRID_WORDS),
+        ];
+        for (random, list) in themes {
+            for _ in 0..50 {
+                let name = random();
+                let key = comparison_key(&name);
+                let found = list.adjectives.iter().any(|adjective| {
+                    key.strip_prefix(&comparison_key(adjective))
+                        .is_some_and(|rest| {
+                            list.nouns.iter().any(|noun| comparison_key(noun) == rest)
+                        })
+                });
+                assert!(found, "{name}");
+            }
+        }
+    }
+
+    #[test]
+    fn themes_share_no_nouns() {
+        let mut seen = HashMap::new();
+        // Hybrid names reuse the food nouns on purpose.
+        for (theme, list) in THEMES.iter().filter(|(theme, _)| *theme != "hybrid") {
+            for noun in list.nouns {
+                if let Some(other) = seen.insert(*noun, *theme) {
+                    panic!("{theme} and {other} both list {noun}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn entropy_seeds_differ() {
+        let states: HashSet<u64> = (0..100).map(|_| NameGenerator::new().state()).collect();
+        assert_eq!(states.len(), 100);
+    }
+
+    #[test]
+    fn seeded_generator_is_deterministic() {
+        let mut one = NameGenerator::from_seed(42);
+        let mut two = NameGenerator::from_seed(42);
+
+        for _ in 0..10 {
+            assert_eq!(one.food_words(), two.food_words());
+            assert_eq!(one.scifi_words(), two.scifi_words());
+        }
+    }
+
+    #[test]
+    fn string_seeds_are_reproducible() {
+        let mut one = NameGenerator::from_seed_str("my-project");
+        let mut two = NameGenerator::from_seed_str("my-project");
+        let mut other = NameGenerator::from_seed_str("my-projects");
+        let names: Vec<_> = (0..5).map(|_| one.food_name()).collect();
+        assert_eq!(names, (0..5).map(|_| two.food_name()).collect::<Vec<_>>());
+        assert_ne!(names, (0..5).map(|_| other.food_name()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn check_digits_catch_misheard_names() {
+        let pair = NamePair {
+            adjective: "zesty",
+            noun: "quasar",
+        };
+        let name = pair.with_check_digit();
+        assert_eq!(name, format!("Zesty Quasar {}", pair.check_digit()));
+        assert!(verify_check_digit(&name));
+        assert!(verify_check_digit(&name.to_lowercase().replace(' ', "-")));
+        let wrong = format!("Zesty Quasar {}", (pair.check_digit() + 1) % 10);
+        assert!(!verify_check_digit(&wrong));
+        assert!(!verify_check_digit("Zesty Quasar"));
+        assert!(!verify_check_digit("Zesty Quasarx 7"));
+    }
+
+    #[test]
+    fn interpolation_runs_between_the_seeds_first_names() {
+        let path = interpolate(1, 2, 6);
+        assert_eq!(path.len(), 6);
+        assert_eq!(path[0], NameGenerator::from_seed(1).food_name());
+        assert_eq!(path[5], NameGenerator::from_seed(2).food_name());
+        assert_eq!(path, interpolate(1, 2, 6));
+        assert_eq!(interpolate(1, 2, 1), path[..1]);
+        assert!(interpolate(1, 2, 0).is_empty());
+    }
+
+    #[test]
+    fn crossover_and_mutation_breed_built_in_pairs() {
+        let mut generator = NameGenerator::from_seed(6);
+        let mango = NamePair {
+            adjective: "shiny",
+            noun: "mango",
+        };
+        let quasar = NamePair {
+            adjective: "zesty",
+            noun: "quasar",
+        };
+        let mut children = HashSet::new();
+        for _ in 0..20 {
+            children.insert(mango.crossover(&quasar, &mut generator));
+        }
+        assert_eq!(children.len(), 2);
+        assert!(children.contains(&NamePair {
+            adjective: "zesty",
+            noun: "mango",
+        }));
+
+        for _ in 0..20 {
+            let mutant = quasar.mutate_weighted(0, 1, &mut generator);
+            assert_eq!(mutant.adjective, "zesty");
+            assert!(mutant.noun != "quasar" && SCIFI_WORDS.nouns.contains(&mutant.noun));
+            let mutant = quasar.mutate_weighted(1, 0, &mut generator);
+            assert!(mutant.adjective != "zesty" && mutant.noun == "quasar");
+        }
+    }
+
+    #[test]
+    fn external_rngs_draw_like_the_generator() {
+        let mut rng = TinyRng::from_seed(5);
+        let mut generator = NameGenerator::from_seed(5);
+        for _ in 0..10 {
+            assert_eq!(food_words_with(|| rng.next_u64()), generator.food_words());
+            assert_eq!(scifi_words_with(|| rng.next_u64()), generator.scifi_words());
+        }
+        let pair = words_with(Theme::Mineral, || 0);
+        assert_eq!(pair.adjective, MINERAL_WORDS.adjectives[0]);
+        assert_eq!(pair.noun, MINERAL_WORDS.nouns[0]);
+    }
+
+    #[test]
+    fn fixtures_follow_the_declared_schema() {
+        let schema = [
+            ("user_id", FixtureColumn::Id),
+            ("display_name", FixtureColumn::Name),
+            ("theme", FixtureColumn::Theme),
+            ("seed", FixtureColumn::Seed),
+        ];
+        let mut names = NameGenerator::from_seed(9).unique();
+        let (first, second) = (names.food_name(), names.food_name());
+
+        let sql = sql_fixtures("users", &schema, Theme::Food, 9, 2);
+        assert_eq!(
+            sql,
+            format!(
+                "INSERT INTO users (user_id, display_name, theme, seed) VALUES\n  \
+                 (1, '{first}', 'food', 9),\n  (2, '{second}', 'food', 9);\n"
+            )
+        );
+        assert_eq!(sql_fixtures("users", &schema, Theme::Food, 9, 0), "");
+
+        let csv = csv_fixtures(&schema[..2], Theme::Food, 9, 2);
+        assert_eq!(
+            csv,
+            format!("user_id,display_name\n1,{first}\n2,{second}\n")
+        );
+        let mut field = String::new();
+        push_csv_field("say \"hi\", then", &mut field);
+        assert_eq!(field, "\"say \"\"hi\"\", then\"");
+    }
+
+    #[test]
+    fn generators_resume_from_a_checkpoint() {
+        let mut generator = NameGenerator::from_seed(11);
+        for _ in 0..5 {
+            generator.food_name();
+        }
+        let mut resumed = NameGenerator::from_state(generator.state());
+        for _ in 0..10 {
+            assert_eq!(resumed.food_name(), generator.food_name());
+        }
+        assert_eq!(
+            NameGenerator::from_state(0).state(),
+            NameGenerator::from_seed(0).state()
+        );
+
+        let mut generator = NameGenerator::new();
+        let mut resumed = NameGenerator::from_state(generator.state());
+        for _ in 0..10 {
+            assert_eq!(resumed.food_name(), generator.food_name());
+        }
+        let mut generator = NameGenerator::new_with(RngVersion::V2);
+        let mut resumed = NameGenerator::from_state_with(generator.state(), RngVersion::V2);
+        assert_eq!(resumed.food_name(), generator.food_name());
+    }
+
+    #[test]
+    fn anonymized_csv_keeps_identifiers_consistent() {
+        let input = "id,note\nu-1,first\nu-2,\"has, comma\"\nu-1,\"multi\nline\"\n,empty\n";
+        let mut out = Vec::new();
+        let mut anonymizer = Anonymizer::new(NameGenerator::from_seed(2), Theme::Food);
+        let rows = anonymizer
+            .rewrite_csv(input.as_bytes(), &mut out, "id")
+            .unwrap();
+        assert_eq!(rows, 4);
+        let (one, two) = (anonymizer.name("u-1"), anonymizer.name("u-2"));
+        assert_ne!(one, two);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("id,note\n{one},first\n{two},\"has, comma\"\n{one},\"multi\nline\"\n,empty\n")
+        );
+
+        let error = anonymizer
+            .rewrite_csv(input.as_bytes(), &mut Vec::new(), "user")
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        let mut out = Vec::new();
+        let rows = anonymizer
+            .rewrite_csv(
+                "note,id\r\nx,u-1\r\n\r\ny,u-2\n\n".as_bytes(),
+                &mut out,
+                "id",
+            )
+            .unwrap();
+        assert_eq!(rows, 2);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("note,id\nx,{one}\ny,{two}\n")
+        );
+    }
+
+    #[test]
+    fn keyed_anonymization_ignores_order_and_run() {
+        let mut first = Anonymizer::new(NameGenerator::from_seed(1), Theme::SciFi).keyed("secret");
+        let mut second = Anonymizer::new(NameGenerator::from_seed(2), Theme::SciFi).keyed("secret");
+        let mut other = Anonymizer::new(NameGenerator::from_seed(1), Theme::SciFi).keyed("other");
+        let ids = ["a", "b", "c", "d"];
+        let names: Vec<_> = ids.iter().map(|id| first.name(id)).collect();
+        for (id, name) in ids.iter().zip(&names).rev() {
+            assert_eq!(&second.name(id), name);
+        }
+        assert_ne!(
+            ids.iter().map(|id| other.name(id)).collect::<Vec<_>>(),
+            names
+        );
+    }
+
+    #[test]
+    fn keyed_anonymization_rehashes_collisions() {
+        let anonymizer = || Anonymizer::new(NameGenerator::from_seed(1), Theme::Mineral).keyed("k");
+        let mut first_choices = HashMap::new();
+        let (early, late) = (0..)
+            .map(|index| format!("user-{index}"))
+            .find_map(|id| {
+                let name = anonymizer().name(&id);
+                first_choices
+                    .insert(name, id.clone())
+                    .map(|other| (other, id))
+            })
+            .unwrap();
+        let name = anonymizer().name(&early);
+
+        let mut forward = anonymizer();
+        assert_eq!(forward.name(&early), name);
+        assert_ne!(forward.name(&late), name);
+        let mut backward = anonymizer();
+        assert_eq!(backward.name(&late), name);
+        assert_ne!(backward.name(&early), name);
+    }
+
+    #[test]
+    fn avatars_are_derived_from_the_pair() {
+        let pair = NamePair {
+            adjective: "shiny",
+            noun: "black hole",
+        };
+        let avatar = pair.avatar();
+        assert_eq!(avatar, pair.avatar());
+        assert_eq!(avatar.initials, "SB");
+        assert!(avatar.shape < AVATAR_SHAPES);
+        assert!(avatar.background.iter().all(|&channel| channel < 200));
+        assert_eq!(avatar.background_hex().len(), 7);
+        assert_eq!(hue_to_rgb(0.0), [166, 48, 48]);
+    }
+
+    #[test]
+    fn reseeding_restarts_the_stream_in_place() {
+        let mut generator = NameGenerator::from_seed(1).with_format(Format::new(Case::Lower, "-"));
+        let first = generator.food_name();
+        generator.food_name();
+        generator.reseed(1);
+        assert_eq!(generator.food_name(), first);
+
+        let before = generator.state();
+        generator.reseed_from_entropy();
+        assert_ne!(generator.state(), before);
+    }
+
+    #[test]
+    fn forks_are_reproducible_and_independent() {
+        let mut root = NameGenerator::from_seed(12);
+        let mut again = NameGenerator::from_seed(12);
+        let (mut one, mut two) = (root.fork(), root.fork());
+        let (mut one_again, _) = (again.fork(), again.fork());
+        let first: Vec<_> = (0..10).map(|_| one.food_words()).collect();
+        let second: Vec<_> = (0..10).map(|_| two.food_words()).collect();
+        assert_ne!(first, second);
+        assert_eq!(
+            first,
+            (0..10).map(|_| one_again.food_words()).collect::<Vec<_>>()
+        );
+        assert_eq!(root.food_words(), again.food_words());
+    }
+
+    #[test]
+    fn entropy_plans_prefer_digits_within_the_length_limit() {
+        let kebab = Format::new(Case::Lower, "-");
+        let (_, longest) = theme_stats()[0].name_lengths(kebab);
+
+        let plan = plan_entropy(Theme::Food, kebab, 10.0, longest).unwrap();
+        assert_eq!((plan.words, plan.suffix_len), (2, 0));
+        assert_eq!(plan.max_len, longest);
+
+        let plan = plan_entropy(Theme::Food, kebab, 20.0, 100).unwrap();
+        assert_eq!((plan.words, plan.suffix_len), (3, 0));
+        assert!(plan.bits >= 20.0);
+        let mut generator = plan.apply(NameGenerator::from_seed(1).with_format(kebab));
+        for _ in 0..20 {
+            let name = generator.food_name();
+            assert!(name.chars().count() <= plan.max_len, "{name}");
+            let (extra, pair) = name.split_once('-').unwrap();
+            assert!(ADJECTIVES.contains(&extra), "{name}");
+            let pair = NamePair::parse(pair).unwrap();
+            assert_ne!(pair.adjective, extra);
+        }
+        let plan = plan_entropy(Theme::Food, kebab, 20.0, longest + 3).unwrap();
+        assert_eq!((plan.words, plan.alphabet), (2, SuffixAlphabet::Digits));
+
+        let plan = plan_entropy(Theme::Food, kebab, 40.0, 100).unwrap();
+        assert_eq!(plan.alphabet, SuffixAlphabet::Digits);
+        assert!(plan.bits >= 40.0);
+        let plan = plan_entropy(Theme::Food, kebab, 40.0, plan.max_len - 1).unwrap();
+        assert_ne!(plan.alphabet, SuffixAlphabet::Digits);
+        assert!(plan.bits >= 40.0);
+        assert!(plan_entropy(Theme::Food, kebab, 200.0, 40).is_none());
+
+        let mut generator = plan.apply(NameGenerator::from_seed(1).with_format(kebab));
+        let name = generator.food_name();
+        assert!(name.chars().count() <= plan.max_len);
+        assert_eq!(name.rsplit_once('-').unwrap().1.len(), plan.suffix_len);
+    }
+
+    #[test]
+    fn jumps_match_drawing_and_streams_are_offset() {
+        let mut drawn = TinyRng::from_seed(21);
+        let mut jumped = drawn;
+        for _ in 0..1000 {
+            drawn.next_u64();
+        }
+        jumped.jump(1000);
+        assert_eq!(jumped.state, drawn.state);
+
+        let root = NameGenerator::from_seed(21);
+        let mut manual = root.clone();
+        manual.jump(2 * STREAM_LENGTH);
+        let mut worker = root.stream(2);
+        assert_eq!(worker.state(), manual.state());
+        assert_eq!(worker.food_words(), manual.food_words());
+        assert_eq!(root.stream(0).state(), root.state());
+        assert_ne!(root.stream(1).state(), worker.state());
+        root.stream((1 << 24) - 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "below 2^24")]
+    fn stream_rejects_worker_ids_that_wrap() {
+        NameGenerator::from_seed(21).stream(1 << 24);
+    }
+
+    crate::theme_functions! {
+        mod mineral_names = Theme::Mineral;
+    }
+
+    #[test]
+    fn theme_functions_match_runtime_dispatch() {
+        let mut fixed = NameGenerator::from_seed(4).only_categories(&[Category::Fruit]);
+        let mut dynamic = fixed.clone();
+        for _ in 0..10 {
+            assert_eq!(
+                mineral_names::words(&mut fixed),
+                dynamic.words(Theme::Mineral)
+            );
+            assert_eq!(
+                mineral_names::name(&mut fixed),
+                dynamic.name(Theme::Mineral)
+            );
+            assert_eq!(
+                fixed.words_of::<mineral_names::Selected>(),
+                dynamic.words(Theme::Mineral)
+            );
+            let fruit = fixed.name_of::<FoodTheme>();
+            assert_eq!(fruit, dynamic.food_name());
+        }
+        assert!(NamePair::parse(&mineral_names::random_name()).is_some());
+    }
+
+    struct FoodTheme;
+
+    impl StaticTheme for FoodTheme {
+        const THEME: Theme = Theme::Food;
+    }
+
+    #[test]
+    fn provenance_tags_identify_the_issuer() {
+        let kebab = Format::new(Case::Lower, "-");
+        let mut workers: Vec<NameGenerator> = (0..4)
+            .map(|tag| {
+                NameGenerator::from_seed(tag.into())
+                    .with_format(kebab)
+                    .with_number_suffix(3)
+                    .with_provenance(tag, 2)
+            })
+            .collect();
+        let reader = NameGenerator::new()
+            .with_format(kebab)
+            .with_number_suffix(3)
+            .with_provenance(0, 2);
+        for (tag, worker) in workers.iter_mut().enumerate() {
+            for _ in 0..10 {
+                let name = worker.scifi_name();
+                assert_eq!(reader.provenance_of(&name), Some(tag as u16), "{name}");
+            }
+        }
+        assert_eq!(NameGenerator::new().provenance_of("shiny-mango"), None);
+    }
+
+    #[test]
+    fn issue_logs_replay_and_catch_tampering() {
+        let mut generator = NameGenerator::from_seed(30);
+        let mut log = Vec::new();
+        let mut names = Vec::new();
+        for theme in [Theme::Food, Theme::SciFi, Theme::Food, Theme::Mineral] {
+            names.push(generator.name_logged(theme, &mut log).unwrap());
+        }
+        let log = String::from_utf8(log).unwrap();
+        assert!(log.starts_with("food\t"));
+        assert_eq!(
+            verify_log(30, log.as_bytes()).unwrap(),
+            LogVerdict::Valid { entries: 4 }
+        );
+
+        let second = log.lines().nth(1).unwrap();
+        let forged = log.replacen(second, "scifi\tshiny\trocket", 1);
+        let LogVerdict::Mismatch { line, expected } = verify_log(30, forged.as_bytes()).unwrap()
+        else {
+            panic!("forged log verified");
+        };
+        assert_eq!((line, expected.title_case()), (2, names[1].clone()));
+
+        let error = verify_log(30, "food\tshiny".as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn rng_versions_are_separate_reproducible_streams() {
+        let draw = |mut generator: NameGenerator| -> Vec<NamePair> {
+            (0..10).map(|_| generator.food_words()).collect()
+        };
+        let v1 = draw(NameGenerator::from_seed_with(5, RngVersion::V1));
+        let v2 = draw(NameGenerator::from_seed_with(5, RngVersion::V2));
+        assert_eq!(v1, draw(NameGenerator::from_seed(5)));
+        assert_eq!(v2, draw(NameGenerator::from_seed_with(5, RngVersion::V2)));
+        assert_ne!(v1, v2);
+
+        let mut v2 = NameGenerator::from_seed_with(5, RngVersion::V2);
+        let mut resumed = NameGenerator::from_state_with(v2.state(), RngVersion::V2);
+        assert_eq!(resumed.food_words(), v2.food_words());
+        assert_eq!(v2.fork().rng_version(), RngVersion::V2);
+
+        let mut drawn = TinyRng::seeded(5, RngVersion::V2);
+        let mut jumped = drawn;
+        for _ in 0..1000 {
+            drawn.next_u64();
+        }
+        jumped.jump(1000);
+        assert_eq!(jumped.state, drawn.state);
+    }
+
+    #[test]
+    fn rename_suggestions_are_stable_unique_and_spread() {
+        let kebab = Format::new(Case::Lower, "-");
+        let ids: Vec<String> = (0..40).map(|n| format!("srv-prod-{n:03}")).collect();
+        let table = suggest_renames(&ids, Theme::SciFi, kebab);
+        assert_eq!(table.len(), 40);
+        assert!(
+            table
+                .iter()
+                .zip(&ids)
+                .all(|((id, _), original)| id == original)
+        );
+
+        let names: HashSet<&String> = table.iter().map(|(_, name)| name).collect();
+        assert_eq!(names.len(), 40);
+        let nouns: HashSet<&str> = table
+            .iter()
+            .map(|(_, name)| NamePair::parse(name).unwrap().noun)
+            .collect();
+        assert_eq!(nouns.len(), 40);
+
+        let mut reversed = ids.clone();
+        reversed.reverse();
+        reversed.push(ids[0].clone());
+        let again = suggest_renames(&reversed, Theme::SciFi, kebab);
+        assert_eq!(again.last(), table.first());
+        assert_eq!(again[0], table[39]);
+    }
+
+    #[test]
+    fn identifiers_are_safe_for_every_word() {
+        let pair = NamePair {
+            adjective: "brisk",
+            noun: "helium-3",
+        };
+        assert_eq!(pair.identifier(), "brisk_helium_3");
+
+        let is_identifier = |text: &str| {
+            text.starts_with(|c: char| c.is_ascii_lowercase())
+                && text
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        };
+        let pair = NamePair {
+            adjective: "Über",
+            noun: "ai nexus",
+        };
+        assert!(is_identifier(&pair.identifier()));
+
+        for list in [&FOOD_WORDS, &SCIFI_WORDS] {
+            for noun in list.nouns {
+                let pair = NamePair {
+                    adjective: ADJECTIVES[0],
+                    noun,
+                };
+                assert!(is_identifier(&pair.identifier()), "{noun}");
+            }
+        }
+    }
+
+    #[test]
+    fn max_len_shortens_with_each_overflow_strategy() {
+        let pair = NamePair {
+            adjective: "shiny",
+            noun: "black cod",
+        };
+        let kebab = Format::new(Case::Lower, "-");
+        assert_eq!(
+            pair.render(kebab.with_max_len(15, Overflow::TruncateNoun)),
+            "shiny-black-cod"
+        );
+        assert_eq!(
+            pair.render(kebab.with_max_len(12, Overflow::TruncateNoun)),
+            "shiny-black"
+        );
+        assert_eq!(
+            pair.render(kebab.with_max_len(10, Overflow::DropAdjective)),
+            "black-cod"
+        );
+        assert_eq!(
+            pair.render(kebab.with_max_len(11, Overflow::Abbreviate)),
+            "s-black-cod"
+        );
+        assert_eq!(
+            pair.render(kebab.with_max_len(3, Overflow::Abbreviate)),
+            "s-b"
+        );
+
+        let mut buf = String::new();
+        pair.write_rendered(&mut buf, kebab.with_max_len(9, Overflow::DropAdjective))
+            .unwrap();
+        assert_eq!(buf, "black-cod");
+
+        for noun in SCIFI_WORDS.nouns {
+            let pair = NamePair {
+                adjective: "glimmering",
+                noun,
+            };
+            for overflow in [
+                Overflow::TruncateNoun,
+                Overflow::DropAdjective,
+                Overflow::Abbreviate,
+            ] {
+                assert!(pair.render(kebab.with_max_len(12, overflow)).len() <= 12);
+            }
+        }
+    }
+
+    #[test]
+    fn dns_labels_follow_rfc_1123() {
+        let pair = NamePair {
+            adjective: "Shiny",
+            noun: "helium-3",
+        };
+        assert_eq!(pair.dns_label(), "shiny-helium-3");
+
+        let long = NamePair {
+            adjective: "glimmering",
+            noun: "interstellar medium interstellar medium interstellar medium",
+        };
+        let label = long.dns_label();
+        assert!(label.len() <= 63);
+        assert!(!label.ends_with('-'));
+
+        for list in [&FOOD_WORDS, &SCIFI_WORDS] {
+            for noun in list.nouns {
+                let label = NamePair {
+                    adjective: ADJECTIVES[0],
+                    noun,
+                }
+                .dns_label();
+                assert!(label.len() <= 63);
+                assert!(label.starts_with(|c: char| c.is_ascii_alphanumeric()));
+                assert!(label.ends_with(|c: char| c.is_ascii_alphanumeric()));
+                assert!(
+                    label
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn write_helpers_append_to_existing_buffers() {
+        let pair = NamePair {
+            adjective: "brisk",
+            noun: "space-time",
+        };
+        let mut buf = String::from("name=");
+        pair.write_snake_case(&mut buf).unwrap();
+        buf.push(' ');
+        pair.write_camel_case(&mut buf).unwrap();
+        buf.push(' ');
+        pair.write_title_case(&mut buf).unwrap();
+        assert_eq!(buf, "name=brisk_space_time briskSpaceTime Brisk Space Time");
+        assert_eq!(format!("[{pair}]"), "[Brisk Space Time]");
+    }
+
+    #[test]
+    fn templates_render_placeholders_and_styles() {
+        let pair = NamePair {
+            adjective: "shiny",
+            noun: "black cod",
+        };
+        let mut rng = TinyRng::from_seed(3);
+        let date = (2024, 3, 9);
+
+        let text = render_template(&pair, "{adjective}-{noun:kebab}", &mut rng, date).unwrap();
+        assert_eq!(text, "shiny-black-cod");
+        let text = render_template(&pair, "{name:pascal}_{date}", &mut rng, date).unwrap();
+        assert_eq!(text, "ShinyBlackCod_2024-03-09");
+        let text = render_template(&pair, "{{{noun:camel}}}", &mut rng, date).unwrap();
+        assert_eq!(text, "{blackCod}");
+
+        let text = render_template(&pair, "{noun:snake}-{rand4}", &mut rng, date).unwrap();
+        let digits = text.strip_prefix("black_cod-").unwrap();
+        assert_eq!(digits.len(), 4);
+        assert!(digits.chars().all(|c| c.is_ascii_digit()));
+
+        assert_eq!(
+            pair.format("{colour}"),
+            Err(TemplateError::UnknownPlaceholder("colour".to_string()))
+        );
+        assert_eq!(
+            pair.format("{noun"),
+            Err(TemplateError::UnclosedPlaceholder)
+        );
+        assert_eq!(pair.format("noun}"), Err(TemplateError::UnmatchedBrace));
+    }
+
+    #[test]
+    fn civil_from_days_matches_known_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn robot_designation_formats_code_and_nickname() {
+        let designation = Designation {
+            series: ['X', 'R'],
+            number: 7,
+            nickname: NamePair {
+                adjective: "buttery",
+                noun: "falcon",
+            },
+        };
+        assert_eq!(designation.code(), "XR-7");
+        assert_eq!(designation.to_string(), "XR-7 'Buttery Falcon'");
+
+        let mut one = NameGenerator::from_seed(7);
+        let mut two = NameGenerator::from_seed(7);
+        for _ in 0..10 {
+            let designation = one.robot_designation();
+            assert_eq!(designation, two.robot_designation());
+            assert!((1..=999).contains(&designation.number));
+            assert!(designation.series.iter().all(|c| c.is_ascii_uppercase()));
+        }
+    }
+
+    #[test]
+    fn excluded_allergens_are_never_generated() {
+        let excluded = [Allergen::Shellfish, Allergen::Gluten];
+        let mut generator = NameGenerator::from_seed(11).exclude_allergens(&excluded);
+        for _ in 0..500 {
+            let pair = generator.food_words();
+            assert!(
+                !food_allergens(pair.noun)
+                    .iter()
+                    .any(|allergen| excluded.contains(allergen)),
+                "{}",
+                pair.noun
+            );
+        }
+
+        let mut plain = NameGenerator::from_seed(11);
+        let mut unfiltered = NameGenerator::from_seed(11).exclude_allergens(&[]);
+        for _ in 0..10 {
+            assert_eq!(plain.food_words(), unfiltered.food_words());
+        }
+    }
+
+    #[test]
+    fn cuisine_filters_constrain_food_nouns() {
+        let mut generator = NameGenerator::from_seed(5).only_cuisines(&[Cuisine::EastAsian]);
+        for _ in 0..200 {
+            let pair = generator.food_words();
+            assert!(
+                food_cuisines(pair.noun).contains(&Cuisine::EastAsian),
+                "{}",
+                pair.noun
+            );
+        }
+
+        let mut generator = NameGenerator::from_seed(5)
+            .only_cuisines(&[Cuisine::Mediterranean])
+            .exclude_allergens(&[Allergen::Gluten, Allergen::Fish]);
+        for _ in 0..200 {
+            let pair = generator.food_words();
+            assert!(food_cuisines(pair.noun).contains(&Cuisine::Mediterranean));
+            let allergens = food_allergens(pair.noun);
+            assert!(!allergens.contains(&Allergen::Gluten) && !allergens.contains(&Allergen::Fish));
+        }
+    }
+
+    #[test]
+    fn cuisine_tags_only_name_food_nouns() {
+        let tagged = [
+            "dumpling",
+            "lychee",
+            "durian",
+            "tamarind",
+            "curry",
+            "ginger",
+            "falafel",
+            "fig",
+            "risotto",
+            "herring",
+            "pretzel",
+            "quinoa",
+            "black cod",
+        ];
+        for noun in tagged {
+            assert!(FOOD_WORDS.nouns.contains(&noun), "{noun}");
+            assert!(!food_cuisines(noun).is_empty());
+        }
+        assert!(food_cuisines("apple").is_empty());
+    }
+
+    #[test]
+    fn tech_level_ceiling_constrains_scifi_nouns() {
+        let mut generator = NameGenerator::from_seed(9).max_tech_level(TechLevel::NearFuture);
+        for _ in 0..300 {
+            let pair = generator.scifi_words();
+            assert!(
+                scifi_tech_level(pair.noun) <= TechLevel::NearFuture,
+                "{}",
+                pair.noun
+            );
+        }
+
+        assert_eq!(scifi_tech_level("weather tower"), TechLevel::NearFuture);
+        assert_eq!(
+            scifi_tech_level("positronic brain"),
+            TechLevel::PostSingularity
+        );
+        assert_eq!(scifi_tech_level("nebula"), TechLevel::Contemporary);
+        for level in [
+            TechLevel::NearFuture,
+            TechLevel::Interstellar,
+            TechLevel::PostSingularity,
+        ] {
+            assert!(
+                SCIFI_WORDS
+                    .nouns
+                    .iter()
+                    .any(|noun| scifi_tech_level(noun) == level)
+            );
+        }
+    }
+
+    #[test]
+    fn allergen_tags_only_name_food_nouns() {
+        let tagged = [
+            "almond",
+            "clams",
+            "cuttlefish",
+            "sushi",
+            "ramen",
+            "noodle",
+            "pizza",
+            "brownie",
+            "granola",
+            "omelet",
+            "miso",
+            "sesame",
+            "black cod",
+            "mahi mahi",
+        ];
+        for noun in tagged {
+            assert!(FOOD_WORDS.nouns.contains(&noun), "{noun}");
+            assert!(!food_allergens(noun).is_empty());
+        }
+        assert!(food_allergens("mango").is_empty());
+    }
+
+    /// Nouns behind `#[cfg(not(feature = "safe"))]` in the word lists.
+    const TRADEMARKED_NOUNS: &[&str] = &[
         "adamantium",
-        "vibranium",
-        "mithril",
+        "beholder",
         "beskar",
-        "unobtanium",
-        "durasteel",
-        "tritanium",
-        "dilithium",
-        "neutronium",
-        "orichalcum",
-        "valyrian steel",
-        "star metal",
-        "nth metal",
-        "plasteel",
-        "nanosteel",
+        "braindance",
         "carbonite",
-        "kyber",
+        "cyberpunk",
+        "deathstar",
+        "element zero",
         "energon",
-        "electrum",
-        "meteoric iron",
-        "graphene",
-        "graphite",
-        "carbon fiber",
-        "nanotube",
-        "nanofiber",
-        "aerogel",
+        "hobbit",
         "kevlar",
-        "mylar",
-        "polymer",
-        "bioplastic",
-        "transparent aluminum",
-        "fused quartz",
-        "obsidian",
-        "quartz",
-        "diamond",
-        "sapphire",
-        "emerald",
-        "ruby",
-        "amethyst",
-        "topaz",
-        "jade",
-        "onyx",
-        "opal",
-        "moonstone",
-        "sunstone",
-        "element zero",
-        "ceramic",
-        "glass",
-        "tempered glass",
-        "fiber optic",
-        "superconductor",
-        "superalloy",
-        "hyperalloy",
-        "memory metal",
-        "living metal",
-        "liquid metal",
-        "smart metal",
-        "bioalloy",
-        "nanoglass",
-        "quantum glass",
-        "helium-3",
-        "tritium",
-        "deuterium",
-        "ferrite",
-        "alloy",
-        "ingot",
-    ],
-};
+        "kyber",
+        "mithril",
+        "mylar",
+        "netrunner",
+        "nth metal",
+        "robocop",
+        "star gate",
+        "telecaster",
+        "tricorder",
+        "tritanium",
+        "valyrian steel",
+        "vibranium",
+    ];
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn safe_feature_controls_trademarked_nouns() {
+        let nouns: HashSet<&str> = THEMES
+            .iter()
+            .flat_map(|(_, list)| list.nouns.iter().copied())
+            .collect();
+        for noun in TRADEMARKED_NOUNS {
+            assert_eq!(nouns.contains(noun), !cfg!(feature = "safe"), "{noun}");
+        }
+    }
 
     #[test]
-    fn title_case_formats_correctly() {
+    fn incident_codenames_are_unique_and_persist() {
+        let mut incidents = Incidents::new(NameGenerator::from_seed(6), Theme::Food);
+        let first = incidents.codename("INC-1").unwrap();
+        assert_eq!(incidents.codename("INC-1"), Some(first));
+        assert_ne!(incidents.codename("INC-2"), Some(first));
+        assert_eq!(incidents.get("INC-3"), None);
+
+        let mut saved = Vec::new();
+        incidents.save(&mut saved).unwrap();
+        let mut restored =
+            Incidents::load(NameGenerator::from_seed(6), Theme::Food, saved.as_slice()).unwrap();
+        assert_eq!(restored.get("INC-1"), Some(first));
+        // Same seed, but the restored codenames are blocked.
+        let third = restored.codename("INC-3").unwrap();
+        assert!(third != first && Some(third) != incidents.get("INC-2"));
+
+        assert!(Incidents::load(NameGenerator::new(), Theme::Food, &b"INC-9\tshiny"[..]).is_err());
+        incidents.codename("bad\tkey");
+        assert!(incidents.save(&mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn codename_variants_render_one_pair() {
+        let codename = Codename(NamePair {
+            adjective: "shiny",
+            noun: "crème brûlée",
+        });
+        assert_eq!(codename.title(), "Shiny Crème Brûlée");
+        assert_eq!(codename.slack_channel(), "inc-shiny-creme-brulee");
+        assert_eq!(codename.doc_title(), "Incident: Shiny Crème Brûlée");
+        assert_eq!(codename.short_code(), "SHI-CRE");
+    }
+
+    #[test]
+    fn every_food_noun_has_a_category() {
+        for noun in FOOD_WORDS.nouns {
+            assert!(noun_category(noun).is_some(), "{noun}");
+        }
+        assert_eq!(noun_category("lobster"), Some(Category::Seafood));
+        assert_eq!(noun_category("mothership"), Some(Category::Vessel));
+        assert_eq!(noun_category("warp"), None);
+        assert_eq!(noun_category("agate"), None);
+    }
+
+    #[test]
+    fn locales_translate_subsets_of_the_english_lists() {
+        for &locale in Locale::ALL {
+            let (food, scifi) = (locale.food(), locale.scifi());
+            assert!(
+                locale
+                    .adjectives()
+                    .iter()
+                    .all(|word| ADJECTIVES.contains(word))
+            );
+            assert!(
+                food.nouns
+                    .iter()
+                    .all(|word| FOOD_WORDS.nouns.contains(word))
+            );
+            assert!(
+                scifi
+                    .nouns
+                    .iter()
+                    .all(|word| SCIFI_WORDS.nouns.contains(word))
+            );
+
+            // Pairs stay English; only the rendered names are translated.
+            let mut pairs = NameGenerator::from_seed(4).with_locale(locale);
+            let mut names = NameGenerator::from_seed(4).with_locale(locale);
+            for _ in 0..20 {
+                let pair = pairs.scifi_words();
+                assert!(scifi.nouns.contains(&pair.noun), "{}", locale.code());
+                assert_eq!(
+                    names.scifi_name(),
+                    locale
+                        .translate_pair(pair)
+                        .render(locale.format(Format::default()))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn category_filters_constrain_nouns() {
+        let mut generator =
+            NameGenerator::from_seed(8).only_categories(&[Category::Seafood, Category::Vessel]);
+        for _ in 0..100 {
+            assert_eq!(
+                noun_category(generator.food_words().noun),
+                Some(Category::Seafood)
+            );
+            assert_eq!(
+                noun_category(generator.scifi_words().noun),
+                Some(Category::Vessel)
+            );
+        }
+
+        let mut old_ships = NameGenerator::from_seed(8)
+            .only_categories(&[Category::Vessel])
+            .max_tech_level(TechLevel::Contemporary);
+        for _ in 0..50 {
+            assert_eq!(
+                scifi_tech_level(old_ships.scifi_words().noun),
+                TechLevel::Contemporary
+            );
+        }
+    }
+
+    #[test]
+    fn adjective_category_filters_constrain_adjectives() {
+        let mut palette = NameGenerator::from_seed(8)
+            .only_adjective_categories(&[AdjectiveCategory::Color])
+            .only_categories(&[Category::Fruit]);
+        let mut moods = NameGenerator::from_seed(8)
+            .only_adjective_categories(&[AdjectiveCategory::Mood, AdjectiveCategory::Speed]);
+        for _ in 0..100 {
+            let pair = palette.food_words();
+            assert_eq!(
+                adjective_category(pair.adjective),
+                Some(AdjectiveCategory::Color)
+            );
+            assert_eq!(noun_category(pair.noun), Some(Category::Fruit));
+            let pair = moods.scifi_words();
+            assert!(matches!(
+                adjective_category(pair.adjective),
+                Some(AdjectiveCategory::Mood | AdjectiveCategory::Speed)
+            ));
+        }
+        assert!(
+            AdjectiveCategory::Speed
+                .adjectives()
+                .any(|word| word == "zippy")
+        );
+        assert_eq!(adjective_category("ancient"), None);
+        assert_eq!(
+            AdjectiveCategory::from_name("texture"),
+            Some(AdjectiveCategory::Texture)
+        );
+    }
+
+    #[test]
+    fn named_entities_keep_their_mascot() {
+        let mut teams = NameGenerator::from_seed(3).with_format(Format::new(Case::Lower, "-"));
+        let mut again = NameGenerator::from_seed(3);
+        for _ in 0..20 {
+            let team = teams.named_entity(Theme::Food, Theme::SciFi);
+            let other = again.named_entity(Theme::Food, Theme::SciFi);
+            assert!(SCIFI_WORDS.nouns.contains(&team.mascot));
+            assert_eq!(team.mascot, other.mascot);
+            assert_eq!(team.name, other.name.to_lowercase().replace(' ', "-"));
+        }
+    }
+
+    #[test]
+    fn category_nouns_group_the_word_lists() {
+        let fruits: Vec<_> = Category::Fruit.nouns().collect();
+        assert!(fruits.contains(&"mango") && !fruits.contains(&"carrot"));
+        let total: usize = Category::ALL.iter().map(|c| c.nouns().count()).sum();
+        let untagged = SCIFI_WORDS
+            .nouns
+            .iter()
+            .filter(|noun| noun_category(noun).is_none())
+            .count();
+        assert_eq!(
+            total + untagged,
+            FOOD_WORDS.nouns.len() + SCIFI_WORDS.nouns.len()
+        );
+        for category in Category::ALL {
+            assert_eq!(Category::from_name(category.name()), Some(category));
+        }
+    }
+
+    #[test]
+    fn vocabulary_export_lists_words_and_tags() {
+        let json = export_vocabulary();
+        assert!(json.starts_with("{\n  \"schema_version\": 2,"));
+        assert!(json.contains(&format!(
+            "\"crate_version\": \"{}\"",
+            env!("CARGO_PKG_VERSION")
+        )));
+        assert!(json.contains(
+            "{\"word\": \"almond\", \"category\": \"nut\", \"allergens\": [\"nuts\"], \"cuisines\": []}"
+        ));
+        assert!(json.contains(
+            "{\"word\": \"warp\", \"category\": null, \"tech_level\": \"interstellar\"}"
+        ));
+        assert!(json.contains("{\"word\": \"quasar\", \"category\": \"celestial\", "));
+        assert!(json.contains("{\"word\": \"agate\", \"category\": null}"));
+        for (name, _) in THEMES {
+            assert!(json.contains(&format!("{{\"name\": \"{name}\"")));
+        }
+        assert!(json.contains("{\"word\": \"amber\", \"category\": \"color\"}"));
+        assert!(json.contains("{\"word\": \"acidic\", \"category\": null}"));
+        let nouns: usize = THEMES.iter().map(|(_, list)| list.nouns.len()).sum();
+        let own_adjectives = HYBRID_WORDS.adjectives.len();
+        assert!(json.contains("{\"name\": \"hybrid\", \"adjectives\": ["));
+        assert_eq!(json.matches("\"adjectives\": [").count(), 2);
+        assert_eq!(
+            json.matches("{\"word\": ").count(),
+            ADJECTIVES.len() + own_adjectives + nouns
+        );
+        assert_eq!(json.matches('{').count(), json.matches('}').count());
+        assert_eq!(json.matches('[').count(), json.matches(']').count());
+
+        let mut escaped = String::new();
+        push_json_string("a\"b\\c\n\u{1}", &mut escaped);
+        assert_eq!(escaped, "\"a\\\"b\\\\c\\n\\u0001\"");
+    }
+
+    #[test]
+    fn name_for_pair_is_symmetric_and_stable() {
+        assert_eq!(name_for_pair("alice", "bob"), name_for_pair("bob", "alice"));
+        // Pinned so an accidental change to the hash shows up as a test failure.
+        assert_eq!(name_for_pair("alice", "bob"), "Ebony Cranberry");
+        assert_ne!(name_for_pair("ab", "c"), name_for_pair("a", "bc"));
+        assert!(NamePair::parse(&name_for_pair("alice", "bob")).is_some());
+        let names: HashSet<_> = (0..50)
+            .map(|index| name_for_pair("alice", &format!("user-{index}")))
+            .collect();
+        assert!(names.len() > 45);
+    }
+
+    #[test]
+    fn network_names_are_stable() {
+        use std::net::{Ipv4Addr, Ipv6Addr};
+
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(name_for_ip(ip), name_for_ip("10.0.0.1".parse().unwrap()));
+        let mapped = IpAddr::V6(Ipv4Addr::new(10, 0, 0, 1).to_ipv6_mapped());
+        assert_eq!(name_for_ip(mapped), name_for_ip(ip));
+        assert_ne!(
+            name_for_ip(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+            name_for_ip(ip)
+        );
+        let mac = [0x00, 0x1b, 0x44, 0x11, 0x3a, 0xb7];
+        assert_eq!(name_for_mac(mac), name_for_mac(mac));
+        assert!(NamePair::parse(&name_for_mac(mac)).is_some());
+    }
+
+    #[test]
+    fn status_names_keep_the_noun_and_follow_the_status() {
+        let mut seen = HashSet::new();
+        for status in Status::ALL {
+            for adjective in status.adjectives() {
+                assert!(ADJECTIVES.contains(adjective), "{adjective}");
+                assert!(seen.insert(adjective), "{adjective} is in two statuses");
+            }
+        }
+
+        let healthy = NamePair::parse(&name_for_status("node-7", Status::Healthy)).unwrap();
+        let critical = NamePair::parse(&name_for_status("node-7", Status::Critical)).unwrap();
+        assert_eq!(healthy.noun, critical.noun);
+        assert!(Status::Healthy.adjectives().contains(&healthy.adjective));
+        assert!(Status::Critical.adjectives().contains(&critical.adjective));
+        assert_eq!(
+            name_for_status("node-7", Status::Critical),
+            name_for_status("node-7", Status::Critical)
+        );
+    }
+
+    #[test]
+    fn time_names_follow_buckets() {
+        use std::time::Duration;
+
+        let at = |seconds: u64| UNIX_EPOCH + Duration::from_secs(seconds);
+        // 1970-01-05 was the first Monday after the epoch.
+        let monday = 4 * 86_400;
+        assert_eq!(
+            name_for_time(at(monday + 10), Granularity::Hour),
+            name_for_time(at(monday + 3_599), Granularity::Hour)
+        );
+        assert_ne!(
+            name_for_time(at(monday), Granularity::Hour),
+            name_for_time(at(monday + 3_600), Granularity::Hour)
+        );
+        assert_eq!(
+            name_for_time(at(monday), Granularity::Week),
+            name_for_time(at(monday + 7 * 86_400 - 1), Granularity::Week)
+        );
+        assert_ne!(
+            name_for_time(at(monday - 1), Granularity::Week),
+            name_for_time(at(monday), Granularity::Week)
+        );
+        assert_ne!(
+            name_for_time(UNIX_EPOCH - Duration::from_secs(1), Granularity::Day),
+            name_for_time(UNIX_EPOCH, Granularity::Day)
+        );
+    }
+
+    #[test]
+    fn coordinate_names_round_trip() {
+        let (lat, lon) = (51.5007, -0.1246);
+        let mut previous = String::new();
+        for precision in 1..=MAX_COORD_PRECISION {
+            let name = name_for_coords(lat, lon, precision);
+            assert_eq!(name.split('.').count(), precision);
+            assert!(name.starts_with(&previous), "{name} after {previous}");
+            previous = name.clone();
+
+            let (centre_lat, centre_lon) = coords_for_name(&name).unwrap();
+            assert_eq!(name_for_coords(centre_lat, centre_lon, precision), name);
+            let cell = 360.0 / (1u64 << (precision as u32 * GEO_PAIR_BITS / 2)) as f64;
+            assert!((centre_lat - lat).abs() <= cell && (centre_lon - lon).abs() <= cell);
+        }
+        assert_eq!(
+            name_for_coords(90.0, 180.0, 2),
+            name_for_coords(95.0, 200.0, 2)
+        );
+        assert_eq!(coords_for_name("not a place"), None);
+        assert_eq!(coords_for_name("warp.warp.warp.warp.warp"), None);
+    }
+
+    #[test]
+    fn syllables_and_lengths_are_reported() {
+        for (word, syllables) in [
+            ("cake", 1),
+            ("apple", 2),
+            ("mango", 2),
+            ("pineapple", 3),
+            ("banana", 3),
+            ("black hole", 2),
+            ("crème brûlée", 3),
+        ] {
+            assert_eq!(count_syllables(word), syllables, "{word}");
+        }
+        let info = WordInfo::of("crème brûlée");
+        assert_eq!((info.chars, info.syllables), (12, 3));
         let pair = NamePair {
             adjective: "shiny",
             noun: "mango",
         };
-        assert_eq!(pair.title_case(), "Shiny Mango");
+        assert_eq!(pair.syllables(), 4);
+
+        let food = theme_stats()
+            .into_iter()
+            .find(|s| s.theme == "food")
+            .unwrap();
+        assert_eq!(food.noun_info().count(), food.nouns());
+        assert!(food.adjective_info().all(|info| info.syllables >= 1));
     }
 
     #[test]
-    fn combinations_exceed_minimums() {
-        assert!(ADJECTIVES.len() * FOOD_WORDS.nouns.len() >= 1000);
-        assert!(ADJECTIVES.len() * SCIFI_WORDS.nouns.len() >= 1000);
+    fn shard_for_is_stable_and_in_range() {
+        assert_eq!(stable_hash(b""), mix64(0xcbf2_9ce4_8422_2325));
+        assert_eq!(shard_for("shiny-mango", 1), 0);
+        // Pinned so an accidental change to the hash shows up as a test failure.
+        assert_eq!(shard_for("shiny-mango", 1000), 736);
+        assert_eq!(shard_for("shiny-mango", 16), 8);
+
+        let mut counts = [0usize; 4];
+        for index in 0..400 {
+            let shard = shard_for(&format!("fixture-{index}"), 4);
+            counts[shard] += 1;
+        }
+        assert!(counts.iter().all(|&count| count > 50), "{counts:?}");
     }
 
     #[test]
-    fn seeded_generator_is_deterministic() {
-        let mut one = NameGenerator::from_seed(42);
-        let mut two = NameGenerator::from_seed(42);
+    fn prefix_suffix_and_format_apply_to_names() {
+        let mut plain = NameGenerator::from_seed(21);
+        let mut host = NameGenerator::from_seed(21)
+            .with_format(Format::new(Case::Lower, "-"))
+            .with_prefix("stage-")
+            .with_suffix("-eu1");
 
         for _ in 0..10 {
-            assert_eq!(one.food_words(), two.food_words());
-            assert_eq!(one.scifi_words(), two.scifi_words());
+            let pair = plain.food_words();
+            assert_eq!(
+                host.food_name(),
+                format!("stage-{}-eu1", pair.render(Format::new(Case::Lower, "-")))
+            );
+        }
+        assert_eq!(
+            NameGenerator::from_seed(4).scifi_name(),
+            NameGenerator::from_seed(4).scifi_words().title_case()
+        );
+    }
+
+    #[test]
+    fn number_suffix_appends_seeded_digits() {
+        let kebab = Format::new(Case::Lower, "-");
+        let mut one = NameGenerator::from_seed(8)
+            .with_format(kebab)
+            .with_number_suffix(4);
+        let mut two = NameGenerator::from_seed(8)
+            .with_format(kebab)
+            .with_number_suffix(4);
+        for _ in 0..10 {
+            let name = one.food_name();
+            assert_eq!(name, two.food_name());
+            let (_, digits) = name.rsplit_once('-').unwrap();
+            assert_eq!(digits.len(), 4);
+            assert!(digits.chars().all(|c| c.is_ascii_digit()));
+        }
+    }
+
+    #[test]
+    fn random_suffixes_use_the_chosen_alphabet() {
+        for alphabet in [
+            SuffixAlphabet::Hex,
+            SuffixAlphabet::Crockford,
+            SuffixAlphabet::Lowercase,
+        ] {
+            let mut generator = NameGenerator::from_seed(8)
+                .with_format(Format::new(Case::Lower, "-"))
+                .with_random_suffix(6, alphabet);
+            let issued: Vec<String> = (0..20).map(|_| generator.food_name()).collect();
+            for name in &issued {
+                let (_, suffix) = name.rsplit_once('-').unwrap();
+                assert_eq!(suffix.len(), 6);
+                assert!(suffix.bytes().all(|c| alphabet.chars().contains(&c)));
+            }
+            let mut fresh = NameGenerator::from_seed(8).with_random_suffix(6, alphabet);
+            assert!(fresh.import_existing(&issued).is_empty());
+        }
+        assert_eq!(SuffixAlphabet::Crockford.chars().len(), 32);
+    }
+
+    #[test]
+    fn lint_flags_awkward_pairs() {
+        let clean = NamePair {
+            adjective: "shiny",
+            noun: "mango",
+        };
+        assert!(lint(&clean).is_empty());
+
+        let doubled = NamePair {
+            adjective: "zesty",
+            noun: "yam",
+        };
+        assert_eq!(lint(&doubled), vec![LintWarning::DoubleLetterAtJoin('y')]);
+
+        let multi = NamePair {
+            adjective: "black",
+            noun: "black cod",
+        };
+        assert!(lint(&multi).contains(&LintWarning::MultiWordNoun));
+
+        let custom = Theme::Custom(Vocabulary::new("sunny", &["sunn"], &["yapple"]).unwrap());
+        let ambiguous = NameGenerator::from_seed(1).words(custom);
+        assert_eq!(
+            lint(&ambiguous),
+            vec![LintWarning::AmbiguousWhenLowercased {
+                adjective: "sunny",
+                noun: "apple"
+            }]
+        );
+    }
+
+    #[test]
+    fn lint_skips_the_lowercase_check_for_built_in_pairs() {
+        let mut generator = NameGenerator::from_seed(3);
+        for theme in Theme::ALL {
+            for _ in 0..200 {
+                let pair = generator.words(theme);
+                assert!(
+                    !lint(&pair).iter().any(|warning| matches!(
+                        warning,
+                        LintWarning::AmbiguousWhenLowercased { .. }
+                    )),
+                    "{pair:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn dedup_against_ignores_case_and_format() {
+        let corpus = vec!["shiny-mango".to_string(), "BRISK_OTTER".to_string()];
+        let batch = vec![
+            "Shiny Mango".to_string(),
+            "Zesty Quasar".to_string(),
+            "BriskOtter".to_string(),
+            "zesty-quasar".to_string(),
+            "Calm Fig".to_string(),
+        ];
+        assert_eq!(
+            dedup_against(batch, corpus),
+            vec!["Zesty Quasar".to_string(), "Calm Fig".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_reads_any_rendering() {
+        let pair = NamePair {
+            adjective: "shiny",
+            noun: "black cod",
+        };
+        for name in [
+            pair.title_case(),
+            pair.snake_case(),
+            pair.camel_case(),
+            pair.uppercase(),
+            pair.dns_label(),
+        ] {
+            assert_eq!(NamePair::parse(&name), Some(pair), "{name}");
+        }
+        assert_eq!(NamePair::parse("shiny unicorn-pony"), None);
+    }
+
+    #[test]
+    fn imported_names_are_never_reissued() {
+        let mut generator = NameGenerator::from_seed(1)
+            .with_format(Format::new(Case::Lower, "-"))
+            .with_prefix("stage-")
+            .with_number_suffix(2);
+        let issued: Vec<String> = (0..50).map(|_| generator.mineral_name()).collect();
+
+        let mut fresh = NameGenerator::from_seed(1)
+            .with_format(Format::new(Case::Lower, "-"))
+            .with_prefix("stage-")
+            .with_number_suffix(2);
+        let unparsed =
+            fresh.import_existing(issued.iter().chain(["not a name".to_string()].iter()));
+        assert_eq!(unparsed, vec!["not a name".to_string()]);
+
+        let issued: HashSet<NamePair> = issued
+            .iter()
+            .map(|name| fresh.parse_issued(name).unwrap())
+            .collect();
+        for _ in 0..500 {
+            assert!(!issued.contains(&fresh.mineral_words()));
+        }
+    }
+
+    #[test]
+    fn usage_report_counts_blocked_pairs_per_theme() {
+        let mut generator = NameGenerator::from_seed(3);
+        for _ in 0..10 {
+            let pair = generator.weather_words();
+            generator.block(pair);
+        }
+
+        let report = generator.usage_report();
+        let weather = report
+            .iter()
+            .find(|usage| usage.theme == "weather")
+            .unwrap();
+        assert_eq!(weather.used, 10);
+        assert_eq!(weather.total, ADJECTIVES.len() * WEATHER_WORDS.nouns.len());
+        assert_eq!(
+            weather.periods_until_exhausted(10.0),
+            Some((weather.total - 10) as f64 / 10.0)
+        );
+        assert_eq!(weather.periods_until_exhausted(0.0), None);
+    }
+
+    fn exhausted_mineral_generator(policy: Exhaustion) -> NameGenerator {
+        let mut generator = NameGenerator::from_seed(8).unique().on_exhaustion(policy);
+        for adjective in ADJECTIVES {
+            for noun in MINERAL_WORDS.nouns {
+                generator.block(NamePair { adjective, noun });
+            }
+        }
+        generator
+    }
+
+    #[test]
+    fn exhausted_incidents_issue_no_codename() {
+        for policy in [
+            Exhaustion::Panic,
+            Exhaustion::NumberSuffix,
+            Exhaustion::ThreeWords,
+        ] {
+            let generator = exhausted_mineral_generator(policy);
+            let mut incidents = Incidents::new(generator, Theme::Mineral);
+            assert_eq!(incidents.codename("INC-1"), None);
+            assert_eq!(incidents.get("INC-1"), None);
+        }
+    }
+
+    #[test]
+    fn exhaustion_policies_keep_names_unique() {
+        let mut numbered = exhausted_mineral_generator(Exhaustion::NumberSuffix);
+        assert!(numbered.mineral_name().ends_with(" 2"));
+        assert!(numbered.mineral_name().ends_with(" 3"));
+
+        let mut escalated = exhausted_mineral_generator(Exhaustion::ThreeWords);
+        let names: HashSet<String> = (0..20).map(|_| escalated.mineral_name()).collect();
+        assert_eq!(names.len(), 20);
+        assert!(names.iter().all(|name| name.split(' ').count() >= 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "combination space")]
+    fn exhaustion_panics_by_default() {
+        exhausted_mineral_generator(Exhaustion::default()).mineral_name();
+    }
+
+    #[test]
+    fn unique_generator_does_not_repeat() {
+        let mut generator = NameGenerator::from_seed(5).unique();
+        let names: HashSet<NamePair> = (0..2000).map(|_| generator.animal_words()).collect();
+        assert_eq!(names.len(), 2000);
+    }
+
+    #[test]
+    fn last_free_pairs_are_always_found() {
+        let last = [
+            NamePair {
+                adjective: SCIFI_WORDS.adjectives[7],
+                noun: SCIFI_WORDS.nouns[3],
+            },
+            NamePair {
+                adjective: SCIFI_WORDS.adjectives[200],
+                noun: SCIFI_WORDS.nouns[100],
+            },
+        ];
+        let mut template = NameGenerator::from_seed(0).unique();
+        for &adjective in SCIFI_WORDS.adjectives {
+            for &noun in SCIFI_WORDS.nouns {
+                let pair = NamePair { adjective, noun };
+                if !last.contains(&pair) {
+                    template.block(pair);
+                }
+            }
+        }
+        for seed in 0..20 {
+            let mut generator = template.clone();
+            generator.reseed(seed);
+            let drawn: HashSet<NamePair> = (0..2).map(|_| generator.scifi_words()).collect();
+            assert_eq!(drawn, HashSet::from(last));
+            assert_eq!(generator.redraws, 0);
+        }
+    }
+
+    #[test]
+    fn key_is_canonical() {
+        let pair = NamePair {
+            adjective: "sunny",
+            noun: "black cod",
+        };
+        assert_eq!(pair.key(), "food:black-cod:sunny");
+        assert_eq!(
+            NamePair::parse(&pair.camel_case()).unwrap().key(),
+            pair.key()
+        );
+    }
+
+    #[test]
+    fn joiner_sits_between_the_words() {
+        let pair = NamePair {
+            adjective: "zesty",
+            noun: "quasar",
+        };
+        assert_eq!(
+            pair.render(Format::default().with_joiner("of")),
+            "Zesty of Quasar"
+        );
+        assert_eq!(
+            pair.render(Format::new(Case::Camel, "").with_joiner("and")),
+            "zestyAndQuasar"
+        );
+
+        let mut generator = NameGenerator::from_seed(4).with_joiner("&");
+        assert!(generator.food_name().contains(" & "));
+    }
+
+    #[test]
+    fn noun_first_orders() {
+        let pair = NamePair {
+            adjective: "zesty",
+            noun: "black cod",
+        };
+        let noun_first = Format::default().with_order(WordOrder::NounFirst);
+        assert_eq!(pair.render(noun_first), "Black Cod Zesty");
+        assert_eq!(
+            pair.render(Format::default().with_order(WordOrder::Inverted)),
+            "Black Cod, Zesty"
+        );
+        assert_eq!(
+            pair.render(noun_first.with_max_len(9, Overflow::Abbreviate)),
+            "Black Cod"
+        );
+    }
+
+    #[test]
+    fn sequence_prefixes_sort_in_issue_order() {
+        let mut generator = NameGenerator::from_seed(6)
+            .with_format(Format::new(Case::Lower, "-"))
+            .with_sequence(Sequence::Counter {
+                start: 41,
+                width: 4,
+            });
+        let names: Vec<String> = (0..3).map(|_| generator.scifi_name()).collect();
+        assert!(names[0].starts_with("0041-"));
+        assert!(names[2].starts_with("0043-"));
+        assert!(names.is_sorted());
+        assert!(generator.parse_issued(&names[1]).is_some());
+
+        let tangy = NamePair {
+            adjective: "tangy",
+            noun: "mango",
+        };
+        let joined = |sequence| {
+            NameGenerator::from_seed(6)
+                .with_format(Format::new(Case::Title, ""))
+                .with_sequence(sequence)
+        };
+        let counter = joined(Sequence::Counter { start: 1, width: 4 });
+        assert_eq!(counter.parse_issued("0042TangyMango"), Some(tangy));
+        assert_eq!(counter.parse_issued("42TangyMango"), None);
+        let stamped = joined(Sequence::Timestamp);
+        assert_eq!(
+            stamped.parse_issued("20240101T093000TangyMango"),
+            Some(tangy)
+        );
+        assert_eq!(stamped.parse_issued("0042TangyMango"), None);
+
+        let stamped = NameGenerator::from_seed(6)
+            .with_sequence(Sequence::Timestamp)
+            .food_name();
+        assert_eq!(stamped.find(' '), Some(15));
+    }
+
+    #[test]
+    fn hybrid_pairs_scifi_modifiers_with_food() {
+        let mut generator = NameGenerator::from_seed(12).exclude_allergens(&[Allergen::Gluten]);
+        for _ in 0..50 {
+            let pair = generator.hybrid_words();
+            assert!(SCIFI_MODIFIERS.contains(&pair.adjective));
+            assert!(FOOD_WORDS.nouns.contains(&pair.noun));
+            assert!(!food_allergens(pair.noun).contains(&Allergen::Gluten));
+        }
+
+        let pair = NamePair {
+            adjective: "quantum",
+            noun: "quinoa",
+        };
+        assert_eq!(NamePair::parse("QuantumQuinoa"), Some(pair));
+        assert_eq!(pair.key(), "hybrid:quinoa:quantum");
+    }
+
+    #[test]
+    fn theme_stats_describe_the_combination_space() {
+        let stats = theme_stats();
+        let food = stats.iter().find(|stats| stats.theme == "food").unwrap();
+        assert_eq!(
+            food.combinations(),
+            ADJECTIVES.len() * FOOD_WORDS.nouns.len()
+        );
+        assert!((food.entropy_bits() - (food.combinations() as f64).log2()).abs() < 1e-9);
+
+        let (min, max) = food.name_lengths(Format::new(Case::Lower, "-"));
+        let lengths: Vec<usize> = ADJECTIVES
+            .iter()
+            .flat_map(|adjective| {
+                FOOD_WORDS.nouns.iter().map(move |noun| {
+                    NamePair { adjective, noun }
+                        .render(Format::new(Case::Lower, "-"))
+                        .len()
+                })
+            })
+            .collect();
+        assert_eq!(min, *lengths.iter().min().unwrap());
+        assert_eq!(max, *lengths.iter().max().unwrap());
+    }
+
+    #[test]
+    fn themes_resolve_by_name() {
+        assert_eq!(Theme::ALL.len(), THEMES.len());
+        for theme in Theme::ALL {
+            assert_eq!(Theme::from_name(theme.name()), Some(theme));
+        }
+        assert_eq!(Theme::from_name("scifi"), Some(Theme::SciFi));
+        assert_eq!(Theme::from_name("sci-fi"), None);
+
+        let mut one = NameGenerator::from_seed(21);
+        let mut two = NameGenerator::from_seed(21);
+        assert_eq!(one.name(Theme::Ocean), two.ocean_name());
+        assert!(
+            MINERAL_WORDS
+                .nouns
+                .contains(&random_words(Theme::Mineral).noun)
+        );
+    }
+
+    #[test]
+    fn registry_resolves_custom_and_built_in_themes() {
+        let mut registry = ThemeRegistry::new();
+        let pirate = registry
+            .register(
+                "pirate",
+                &["salty", "Jolly"],
+                &["parrot", "cutlass", "plank"],
+            )
+            .unwrap();
+        assert_eq!(registry.get("pirate"), Some(pirate));
+        assert_eq!(registry.get("food"), Some(Theme::Food));
+        assert_eq!(registry.get("ninja"), None);
+
+        let mut generator = NameGenerator::from_seed(2);
+        let pair = generator.words(pirate);
+        assert!(["salty", "Jolly"].contains(&pair.adjective));
+        assert!(registry.name("pirate", &mut generator).is_some());
+    }
+
+    #[test]
+    fn custom_words_are_seeded() {
+        let adjectives = ["brisk", "mellow", "vivid"];
+        let nouns = ["harbor", "lantern"];
+        let mut one = NameGenerator::from_seed(30)
+            .with_words(&adjectives, &nouns)
+            .unwrap();
+        let mut two = NameGenerator::from_seed(30)
+            .with_words(&adjectives, &nouns)
+            .unwrap();
+        for _ in 0..10 {
+            let name = one.custom_name();
+            assert_eq!(name, two.custom_name());
+            assert!(name.ends_with("Harbor") || name.ends_with("Lantern"));
+        }
+        assert!(
+            NameGenerator::new()
+                .with_words(&adjectives, &[] as &[&str])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn themes_load_from_json_toml_and_lines() {
+        let json = r#"{"adjectives": ["brisk", "caf\u00e9"], "nouns": ["harbor", "lantern"]}"#;
+        let toml = "# harbor words\nadjectives = ['brisk', \"café\"]\nnouns = [\n  \"harbor\",\n  \"lantern\", # lit\n]\n";
+        let lines = "[adjectives]\nbrisk\ncafé\n\n# nouns follow\n[nouns]\nharbor\nlantern\n";
+        let themes = [
+            Theme::from_json("harbor", json).unwrap(),
+            Theme::from_toml("harbor", toml).unwrap(),
+            Theme::from_lines("harbor", lines.as_bytes()).unwrap(),
+        ];
+        assert_eq!(themes[0], themes[1]);
+        assert_eq!(themes[0], themes[2]);
+        assert_eq!(themes[0].name(), "harbor");
+
+        assert!(matches!(
+            Theme::from_json("x", "{\"adjectives\": [\"brisk\"],\n \"verbs\": []}"),
+            Err(ThemeLoadError::Syntax { line: 2, .. })
+        ));
+        assert!(matches!(
+            Theme::from_lines("x", "brisk\n".as_bytes()),
+            Err(ThemeLoadError::Syntax { line: 1, .. })
+        ));
+        assert!(matches!(
+            Theme::from_toml("x", "adjectives = [\"brisk\"]"),
+            Err(ThemeLoadError::Vocabulary(VocabularyError::NoNouns))
+        ));
+    }
+
+    define_theme! {
+        HARBOR = "harbor" {
+            adjectives: ["misty", "Brisk", "salty"],
+            nouns: ["lighthouse", "jetty", "buoy"],
+        }
+    }
+
+    #[test]
+    fn defined_themes_match_runtime_vocabularies() {
+        let runtime = Vocabulary::new(
+            "harbor",
+            &["misty", "Brisk", "salty"],
+            &["lighthouse", "jetty", "buoy"],
+        )
+        .unwrap();
+        assert_eq!(HARBOR.name(), "harbor");
+
+        let mut one = NameGenerator::from_seed(17);
+        let mut two = NameGenerator::from_seed(17);
+        for _ in 0..10 {
+            let pair = one.words(HARBOR);
+            assert_eq!(pair, two.words(Theme::Custom(runtime)));
+        }
+    }
+
+    #[test]
+    fn invalid_vocabularies_are_rejected() {
+        let nouns = ["kelp"];
+        assert_eq!(
+            Vocabulary::new("x", &[] as &[&str], &nouns),
+            Err(VocabularyError::NoAdjectives)
+        );
+        assert_eq!(
+            Vocabulary::new("x", &["salty", "  "], &nouns),
+            Err(VocabularyError::BlankWord)
+        );
+        assert_eq!(
+            Vocabulary::new("x", &["salty", "Salty"], &nouns),
+            Err(VocabularyError::DuplicateWord("Salty".to_string()))
+        );
+        assert_eq!(
+            Vocabulary::new("x", &["golden", "gold"], &["enigma", "igma"]),
+            Err(VocabularyError::AmbiguousPairs)
+        );
+    }
+
+    #[test]
+    fn simulation_reports_collisions_and_redraws() {
+        let generator = NameGenerator::from_seed(40);
+        let plain = generator.simulate(Theme::Celestial, 500, 20);
+        assert!(plain.mean_collisions > 0.0);
+        assert_eq!(plain.mean_redraws, 0.0);
+        assert_eq!(plain, generator.simulate(Theme::Celestial, 500, 20));
+
+        let unique = generator
+            .clone()
+            .unique()
+            .simulate(Theme::Celestial, 500, 20);
+        assert_eq!(unique.mean_collisions, 0.0);
+        assert_eq!(unique.collision_probability, 0.0);
+        assert!(unique.mean_redraws > 0.0);
+
+        let numbered = generator
+            .with_number_suffix(4)
+            .simulate(Theme::Celestial, 500, 20);
+        assert!(numbered.mean_collisions < plain.mean_collisions);
+    }
+
+    #[test]
+    fn tokens_split_multi_word_nouns() {
+        let pair = NamePair {
+            adjective: "smoky",
+            noun: "mahi mahi",
+        };
+        assert_eq!(pair.tokens().collect::<Vec<_>>(), ["smoky", "mahi", "mahi"]);
+    }
+
+    #[test]
+    fn colliding_vocabulary_is_rejected() {
+        let spaced: &[&str] = &["sea weed", "kelp"];
+        let joined: &[&str] = &["seaweed", "enigma", "igma"];
+        assert!(pairs_are_distinguishable(&["gold"], &[spaced]));
+        assert!(!pairs_are_distinguishable(&["gold"], &[spaced, joined]));
+        assert!(!pairs_are_distinguishable(&["gold", "golden"], &[joined]));
+        assert!(!pairs_are_distinguishable(&["golden", "gold"], &[]));
+        // `abÉ` + `x` and `ab` + `éx` only meet once `É` is lowercased.
+        assert_eq!(
+            Vocabulary::new("accents", &["ab", "abÉ"], &["éx", "x"]),
+            Err(VocabularyError::AmbiguousPairs)
+        );
+    }
+
+    #[test]
+    fn every_format_keeps_the_comparison_key() {
+        let formats = [Case::Title, Case::Lower, Case::Upper, Case::Camel]
+            .into_iter()
+            .flat_map(|case| ["", " ", "-", "_"].map(|separator| Format::new(case, separator)));
+        for (_, list) in THEMES {
+            for noun in list.nouns {
+                let pair = NamePair {
+                    adjective: "golden",
+                    noun,
+                };
+                let key = comparison_key(&format!("{}{}", pair.adjective, pair.noun));
+                let ascii_key =
+                    comparison_key(&pair.render(Format::new(Case::Lower, "").ascii_only()));
+                for format in formats.clone() {
+                    assert_eq!(comparison_key(&pair.render(format)), key);
+                    assert_eq!(comparison_key(&pair.render(format.ascii_only())), ascii_key);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dessert_nouns_are_sorted_and_ascii() {
+        assert!(DESSERT_WORDS.nouns.is_sorted());
+        assert!(DESSERT_WORDS.nouns.iter().all(|noun| noun.is_ascii()));
+    }
+
+    #[test]
+    fn ascii_folding_keeps_nouns_distinct() {
+        let mut seen = HashMap::new();
+        for (_, list) in THEMES {
+            for noun in list.nouns {
+                let folded = comparison_key(&render_words(&[noun], Format::default().ascii_only()));
+                let previous = seen.insert(folded, *noun);
+                assert!(previous.is_none_or(|previous| previous == *noun), "{noun}");
+            }
         }
+        assert!(ADJECTIVES.iter().all(|adjective| adjective.is_ascii()));
     }
 
     #[test]
@@ -1,15 +1,226 @@
-use sci_fi_food::NameGenerator;
+use std::env;
+use std::process;
+use std::str::FromStr;
+
+use sci_fi_food::{Case, Exhaustion, Format, NameGenerator, Theme, theme_stats};
+
+const FOOD_COUNT: usize = 20;
+const SCIFI_COUNT: usize = 24;
+
+/// Formats reported by `stats`, by the names templates use for them.
+const STATS_FORMATS: &[(&str, Format<'static>)] = &[
+    ("title", Format::new(Case::Title, " ")),
+    ("kebab", Format::new(Case::Lower, "-")),
+    ("snake", Format::new(Case::Lower, "_")),
+    ("pascal", Format::new(Case::Title, "")),
+    ("camel", Format::new(Case::Camel, "")),
+];
 
 fn main() {
+    let mut args = env::args().skip(1).peekable();
+    match args.peek().map(String::as_str) {
+        Some("stats") => {
+            args.next();
+            return stats(args);
+        }
+        Some("simulate") => {
+            args.next();
+            return simulate(args);
+        }
+        _ => {}
+    }
+
+    let mut start_index: usize = 1;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--start-index" => {
+                start_index = args
+                    .next()
+                    .and_then(|value| value.parse().ok())
+                    .unwrap_or_else(|| fail("--start-index needs a non-negative number"));
+            }
+            _ => fail(&format!("unknown argument `{arg}`")),
+        }
+    }
+
+    if start_index
+        .checked_add(FOOD_COUNT.max(SCIFI_COUNT) - 1)
+        .is_none()
+    {
+        fail("--start-index is too large to number the list");
+    }
+
     let mut generator = NameGenerator::new();
 
     println!("Food combinations:");
-    for index in 1..=20 {
-        println!("{:02}. {}", index, generator.food_name());
-    }
+    print_numbered(start_index, FOOD_COUNT, || generator.food_name());
 
     println!("\nSci-Fi combinations:");
-    for index in 1..=24 {
-        println!("{:02}. {}", index, generator.scifi_name());
+    print_numbered(start_index, SCIFI_COUNT, || generator.scifi_name());
+}
+
+/// Print `count` names numbered from `start`, zero-padded to the width of the last number.
+fn print_numbered(start: usize, count: usize, mut name: impl FnMut() -> String) {
+    let last = count
+        .checked_sub(1)
+        .and_then(|offset| start.checked_add(offset))
+        .unwrap_or_else(|| fail("--start-index is too large to number the list"));
+    let width = last.to_string().len().max(2);
+    for index in start..=last {
+        println!("{index:0width$}. {}", name());
+    }
+}
+
+/// `stats [--output text|json]`: size of every theme's combination space.
+fn stats(mut args: impl Iterator<Item = String>) {
+    let mut json = false;
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next().as_deref()) {
+            ("--output", Some("json")) => json = true,
+            ("--output", Some("text")) => json = false,
+            ("--output", _) => fail("--output needs `text` or `json`"),
+            _ => fail(&format!("unknown argument `{arg}`")),
+        }
+    }
+
+    if json {
+        print!("{}", stats_json());
+        return;
     }
+    println!(
+        "{:<12} {:>12} {:>8}  title length",
+        "theme", "combinations", "bits"
+    );
+    for stats in theme_stats() {
+        let (min, max) = stats.name_lengths(Format::default());
+        println!(
+            "{:<12} {:>12} {:>8.2}  {min}-{max}",
+            stats.theme,
+            stats.combinations(),
+            stats.entropy_bits()
+        );
+    }
+}
+
+fn stats_json() -> String {
+    let mut json = String::from("{\n  \"themes\": [");
+    for (index, stats) in theme_stats().iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+        json.push_str(&format!(
+            "\n    {{\"name\": \"{}\", \"adjectives\": {}, \"nouns\": {}, \"combinations\": {}, \
+             \"entropy_bits\": {:.4}, \"name_lengths\": {{",
+            stats.theme,
+            stats.adjectives(),
+            stats.nouns(),
+            stats.combinations(),
+            stats.entropy_bits()
+        ));
+        for (format_index, (format_name, format)) in STATS_FORMATS.iter().enumerate() {
+            if format_index > 0 {
+                json.push_str(", ");
+            }
+            let (min, max) = stats.name_lengths(*format);
+            json.push_str(&format!(
+                "\"{format_name}\": {{\"min\": {min}, \"max\": {max}}}"
+            ));
+        }
+        json.push_str("}}");
+    }
+    json.push_str("\n  ]\n}\n");
+    json
+}
+
+/// `simulate --count N [--theme T] [--digits D] [--unique] [--on-exhaustion P] [--trials K]
+/// [--seed S]`: expected collisions and redraws when issuing `N` names.
+fn simulate(mut args: impl Iterator<Item = String>) {
+    let (mut theme, mut count, mut digits, mut unique, mut trials) =
+        (Theme::Food, None, 0, false, 100);
+    let mut seed: Option<u64> = None;
+    let mut exhaustion = Exhaustion::Panic;
+    while let Some(arg) = args.next() {
+        if arg == "--unique" {
+            unique = true;
+            continue;
+        }
+        let value = args
+            .next()
+            .unwrap_or_else(|| fail(&format!("{arg} needs a value")));
+        match arg.as_str() {
+            "--theme" => {
+                theme = Theme::from_name(&value)
+                    .unwrap_or_else(|| fail(&format!("unknown theme `{value}`")));
+            }
+            "--count" => count = Some(number(&arg, &value)),
+            "--digits" => digits = number(&arg, &value),
+            "--trials" => trials = number::<usize>(&arg, &value).max(1),
+            "--seed" => seed = Some(number(&arg, &value)),
+            "--on-exhaustion" => {
+                exhaustion = match value.as_str() {
+                    "error" => Exhaustion::Panic,
+                    "number" => Exhaustion::NumberSuffix,
+                    "three-words" => Exhaustion::ThreeWords,
+                    _ => fail("--on-exhaustion needs `error`, `number` or `three-words`"),
+                };
+            }
+            _ => fail(&format!("unknown argument `{arg}`")),
+        }
+    }
+    let count = count.unwrap_or_else(|| fail("simulate needs --count"));
+    if unique {
+        check_unique_count(theme, count, exhaustion);
+    }
+
+    let mut generator = seed.map_or_else(NameGenerator::new, NameGenerator::from_seed);
+    generator = generator
+        .with_number_suffix(digits)
+        .on_exhaustion(exhaustion);
+    if unique {
+        generator = generator.unique();
+    }
+    let result = generator.simulate(theme, count, trials);
+    println!("theme:                 {}", theme.name());
+    println!("names per trial:       {}", result.names);
+    println!("trials:                {}", result.trials);
+    println!("mean collisions:       {:.3}", result.mean_collisions);
+    println!("collision probability: {:.3}", result.collision_probability);
+    println!("mean redraws:          {:.3}", result.mean_redraws);
+}
+
+/// Reject a `--unique` run that would use up `theme` before `count` names under `exhaustion`.
+fn check_unique_count(theme: Theme, count: usize, exhaustion: Exhaustion) {
+    let stats = theme_stats()
+        .into_iter()
+        .find(|stats| stats.theme == theme.name())
+        .expect("built-in themes have stats");
+    let available = match exhaustion {
+        Exhaustion::Panic => stats.combinations(),
+        Exhaustion::NumberSuffix => return,
+        Exhaustion::ThreeWords => stats.combinations().saturating_mul(stats.adjectives()),
+    };
+    if count > available {
+        fail(&format!(
+            "--count {count} exceeds the {available} unique names of theme `{}`; pass \
+             --on-exhaustion number or three-words, or lower --count",
+            theme.name()
+        ));
+    }
+}
+
+fn number<T: FromStr>(arg: &str, value: &str) -> T {
+    value
+        .parse()
+        .unwrap_or_else(|_| fail(&format!("{arg} needs a number")))
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("error: {message}");
+    eprintln!("usage: sci_fi_food [--start-index N]");
+    eprintln!("       sci_fi_food stats [--output text|json]");
+    eprintln!(
+        "       sci_fi_food simulate --count N [--theme T] [--digits D] [--unique] \
+         [--on-exhaustion error|number|three-words] [--trials K] [--seed S]"
+    );
+    process::exit(2);
 }